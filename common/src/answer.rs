@@ -0,0 +1,93 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle, independent of the type the
+/// solver produced it as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    // Answers which are read off a rendered grid, such as day 13's
+    // activation code, one string per row
+    Lines(Vec<String>),
+    // Some parts have no puzzle to solve (day 25 part 2)
+    Empty,
+    // Inputs which parse may still have no answer, such as a bingo game
+    // which no board wins, for the reason given
+    Unsolvable(String),
+}
+
+/// Why a part has no answer for an input which parsed, for parts which
+/// only find out by solving the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoAnswer(pub String);
+
+impl NoAnswer {
+    pub fn new(reason: impl Into<String>) -> NoAnswer {
+        NoAnswer(reason.into())
+    }
+}
+
+impl fmt::Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no answer, as {}", self.0)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+            Answer::Empty => Ok(()),
+            Answer::Unsolvable(reason) => write!(f, "{}", NoAnswer(reason.clone())),
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(number: $t) -> Self {
+                    Answer::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(u32, u64, usize, i32, i64, i128);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(String::from(text))
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(lines: Vec<String>) -> Self {
+        Answer::Lines(lines)
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::Empty
+    }
+}
+
+impl<T: Into<Answer>> From<Result<T, NoAnswer>> for Answer {
+    fn from(result: Result<T, NoAnswer>) -> Self {
+        match result {
+            Ok(answer) => answer.into(),
+            Err(NoAnswer(reason)) => Answer::Unsolvable(reason),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{parse_span, Problem};
+use pest::iterators::{Pair, Pairs};
+use pest::RuleType;
+use std::fmt;
+use std::str::FromStr;
+
+/// A value taken from the pairs matched by a rule's children, in order,
+/// such as a number from a `value` or a tuple of numbers from a line.
+pub trait Capture<'i>: Sized {
+    fn capture<R: RuleType>(pairs: &mut Pairs<'i, R>) -> Result<Self, Problem>;
+}
+
+/// Capture the children of `pair`, for example the three numbers of
+/// `beacon = { signed_value ~ "," ~ signed_value ~ "," ~ signed_value }`
+/// as an `(i32, i32, i32)`.
+pub fn capture<'i, R, T>(pair: Pair<'i, R>) -> Result<T, Problem>
+where
+    R: RuleType,
+    T: Capture<'i>,
+{
+    T::capture(&mut pair.into_inner())
+}
+
+// The grammar decides how many children a rule has, so running out of
+// them is a mistake in the grammar or the type captured, not in the input
+fn next_pair<'i, R: RuleType>(pairs: &mut Pairs<'i, R>) -> Pair<'i, R> {
+    pairs.next().expect("rule has fewer children than captured")
+}
+
+fn capture_parsed<'i, R, T>(pairs: &mut Pairs<'i, R>) -> Result<T, Problem>
+where
+    R: RuleType,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    parse_span(next_pair(pairs).as_span())
+}
+
+macro_rules! capture_numbers {
+    ($($number:ty),*) => {
+        $(
+            impl<'i> Capture<'i> for $number {
+                fn capture<R: RuleType>(pairs: &mut Pairs<'i, R>) -> Result<Self, Problem> {
+                    capture_parsed(pairs)
+                }
+            }
+        )*
+    };
+}
+
+capture_numbers!(u8, u32, u64, usize, i32, i64);
+
+/// The text matched, such as "on" or "off".
+impl<'i> Capture<'i> for &'i str {
+    fn capture<R: RuleType>(pairs: &mut Pairs<'i, R>) -> Result<Self, Problem> {
+        Ok(next_pair(pairs).as_str())
+    }
+}
+
+/// The first character matched, such as a letter.
+impl<'i> Capture<'i> for char {
+    fn capture<R: RuleType>(pairs: &mut Pairs<'i, R>) -> Result<Self, Problem> {
+        let pair = next_pair(pairs);
+
+        pair.as_str()
+            .chars()
+            .next()
+            .ok_or_else(|| Problem::at_span(pair.as_span(), "expected a character"))
+    }
+}
+
+/// Every child left, which must all be the same type.
+impl<'i, T: Capture<'i>> Capture<'i> for Vec<T> {
+    fn capture<R: RuleType>(pairs: &mut Pairs<'i, R>) -> Result<Self, Problem> {
+        let mut values = Vec::new();
+        while pairs.peek().is_some() {
+            values.push(T::capture(pairs)?);
+        }
+
+        Ok(values)
+    }
+}
+
+macro_rules! capture_tuple {
+    ($($name:ident),*) => {
+        impl<'i, $($name: Capture<'i>),*> Capture<'i> for ($($name,)*) {
+            fn capture<R: RuleType>(pairs: &mut Pairs<'i, R>) -> Result<Self, Problem> {
+                Ok(($($name::capture(pairs)?,)*))
+            }
+        }
+    };
+}
+
+capture_tuple!(A, B);
+capture_tuple!(A, B, C);
+capture_tuple!(A, B, C, D);
+capture_tuple!(A, B, C, D, E);
+capture_tuple!(A, B, C, D, E, F);
+capture_tuple!(A, B, C, D, E, F, G);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pest::Parser;
+    use pest_derive::Parser;
+
+    #[derive(Parser)]
+    #[grammar = "numbers.pest"]
+    #[grammar_inline = r#"
+        triple = { signed_value ~ "," ~ value ~ "," ~ signed_value }
+        list = { value ~ (" " ~ value)* }
+    "#]
+    struct TestParser;
+
+    fn parse(rule: Rule, input: &str) -> Pair<'_, Rule> {
+        TestParser::parse(rule, input).unwrap().next().unwrap()
+    }
+
+    #[test]
+    fn signed_and_unsigned_values() {
+        let (a, b, c): (i32, u32, i64) = capture(parse(Rule::triple, "-12,7,3")).unwrap();
+        assert_eq!((a, b, c), (-12, 7, 3));
+
+        let values: Vec<u8> = capture(parse(Rule::list, "1 22 3")).unwrap();
+        assert_eq!(values, vec![1, 22, 3]);
+
+        assert!(TestParser::parse(Rule::triple, "1,-7,3").is_err());
+        assert!(TestParser::parse(Rule::triple, "-,7,3").is_err());
+    }
+
+    #[test]
+    fn values_which_do_not_fit() {
+        let problem =
+            capture::<_, (i32, u32, i32)>(parse(Rule::triple, "1,2,99999999999")).unwrap_err();
+
+        assert_eq!(problem.column, 5);
+        assert!(problem.message.starts_with("invalid number"));
+    }
+}
@@ -0,0 +1,271 @@
+use pest::error::{Error as PestError, LineColLocation};
+use pest::RuleType;
+use pest::Span;
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// One problem found in a puzzle input, located by its 1-based line and
+/// column and carrying the text of the line so that it can be shown with
+/// a caret under the offending span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub line: usize,
+    pub column: usize,
+    pub line_text: String,
+    pub span: String,
+    pub message: String,
+}
+
+impl Problem {
+    pub fn new(
+        line: usize,
+        column: usize,
+        line_text: &str,
+        span: &str,
+        message: impl Into<String>,
+    ) -> Problem {
+        Problem {
+            line,
+            column,
+            line_text: String::from(line_text),
+            span: String::from(span),
+            message: message.into(),
+        }
+    }
+
+    // A problem with the text matched by a pest rule
+    pub fn at_span(span: Span<'_>, message: impl Into<String>) -> Problem {
+        let (line, column) = span.start_pos().line_col();
+        let line_text = span.start_pos().line_of().trim_end_matches(['\r', '\n']);
+
+        // Rules which end at a newline shouldn't mark it
+        let text = span.as_str().trim_end_matches(['\r', '\n']);
+
+        Problem::new(line, column, line_text, text, message)
+    }
+
+    // A problem past the last line of the input, such as a missing line
+    pub fn at_end(input: &str, message: impl Into<String>) -> Problem {
+        Problem::after_lines(input.lines().count(), message)
+    }
+
+    // A problem past the last line of an input of `lines` lines, for inputs
+    // which are read a line at a time
+    pub fn after_lines(lines: usize, message: impl Into<String>) -> Problem {
+        Problem::new(lines + 1, 1, "", "", message)
+    }
+}
+
+impl<R: RuleType> From<PestError<R>> for Problem {
+    fn from(error: PestError<R>) -> Self {
+        let line_text = error.line().trim_end_matches(['\r', '\n']);
+        let (line, column, span) = match error.line_col {
+            LineColLocation::Pos((line, column)) => (line, column, ""),
+            LineColLocation::Span((line, column), (end_line, end_column)) => {
+                let start = line_text
+                    .char_indices()
+                    .nth(column - 1)
+                    .map_or(line_text.len(), |(j, _)| j);
+                let end = if end_line == line {
+                    line_text
+                        .char_indices()
+                        .nth(end_column - 1)
+                        .map_or(line_text.len(), |(j, _)| j)
+                } else {
+                    line_text.len()
+                };
+                (line, column, &line_text[start..end])
+            }
+        };
+
+        Problem::new(line, column, line_text, span, error.variant.message())
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let indent: String = self
+            .line_text
+            .chars()
+            .take(self.column - 1)
+            .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+            .collect();
+        let carets = "^".repeat(self.span.chars().count().max(1));
+
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.line_text)?;
+        writeln!(f, "{} | {}{}", gutter, indent, carets)
+    }
+}
+
+/// Every problem found while parsing one puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub problems: Vec<Problem>,
+}
+
+impl ParseError {
+    pub fn new(problems: Vec<Problem>) -> ParseError {
+        ParseError {
+            file: None,
+            problems,
+        }
+    }
+
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> ParseError {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// Move every problem down by `lines`, for problems found in a part of
+    /// the input which does not start on its first line.
+    pub fn below_lines(mut self, lines: usize) -> ParseError {
+        for problem in &mut self.problems {
+            problem.line += lines;
+        }
+        self
+    }
+}
+
+impl From<Problem> for ParseError {
+    fn from(problem: Problem) -> Self {
+        ParseError::new(vec![problem])
+    }
+}
+
+impl<R: RuleType> From<PestError<R>> for ParseError {
+    fn from(error: PestError<R>) -> Self {
+        ParseError::from(Problem::from(error))
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = match &self.file {
+            Some(file) => file.display().to_string(),
+            None => String::from("<input>"),
+        };
+
+        for (j, problem) in self.problems.iter().enumerate() {
+            if j > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "error: {}", problem.message)?;
+            writeln!(f, " --> {}:{}:{}", file, problem.line, problem.column)?;
+            write!(f, "{}", problem)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse each line of the input with `parse_line`, which reports problems
+/// as if its line were the first line of the input. Problems from every
+/// line are collected, rather than stopping at the first bad line.
+pub fn parse_lines<T, F>(input: &str, mut parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    let mut values = Vec::new();
+    let mut problems = Vec::new();
+
+    for (j, line) in input.lines().enumerate() {
+        match parse_line(line) {
+            Ok(value) => values.push(value),
+            Err(error) => {
+                problems.extend(error.problems.into_iter().map(|mut problem| {
+                    problem.line += j;
+                    problem
+                }));
+            }
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(values)
+    } else {
+        Err(ParseError::new(problems))
+    }
+}
+
+/// Parse the text matched by a pest rule, such as a number which may not
+/// fit in `T`.
+pub fn parse_span<T>(span: Span<'_>) -> Result<T, Problem>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    span.as_str()
+        .parse::<T>()
+        .map_err(|e| Problem::at_span(span, format!("invalid number: {}", e)))
+}
+
+/// Parse `text`, found at the 1-based `column` of `line_text`.
+pub fn parse_text<T>(line_text: &str, column: usize, text: &str) -> Result<T, Problem>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text.parse::<T>()
+        .map_err(|e| Problem::new(1, column, line_text, text, format!("invalid number: {}", e)))
+}
+
+/// Parse a line of values separated by `separator`, such as "3,4,3,1,2",
+/// collecting the problems with every value.
+pub fn parse_separated<T>(line: &str, separator: char) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let mut values = Vec::new();
+    let mut problems = Vec::new();
+    let mut column = 1;
+
+    for text in line.split(separator) {
+        match parse_text(line, column, text) {
+            Ok(value) => values.push(value),
+            Err(problem) => problems.push(problem),
+        }
+        column += text.chars().count() + 1;
+    }
+
+    if problems.is_empty() {
+        Ok(values)
+    } else {
+        Err(ParseError::new(problems))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_caret_under_span() {
+        let error = ParseError::from(Problem::new(3, 6, "down x7", "x7", "invalid number"))
+            .with_file("input.txt");
+
+        assert_eq!(
+            error.to_string(),
+            "error: invalid number\n --> input.txt:3:6\n  |\n3 | down x7\n  |      ^^\n"
+        );
+    }
+
+    #[test]
+    fn parse_lines_collects_every_problem() {
+        let input = "1\nx\n3\ny";
+        let error = parse_lines(input, |line| {
+            parse_text::<u32>(line, 1, line).map_err(ParseError::from)
+        })
+        .unwrap_err();
+
+        let lines: Vec<usize> = error.problems.iter().map(|problem| problem.line).collect();
+        assert_eq!(lines, vec![2, 4]);
+    }
+}
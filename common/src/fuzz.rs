@@ -0,0 +1,19 @@
+use crate::Solution;
+use std::str;
+
+/// Parse arbitrary bytes as a day's input, for fuzzing. The parse may fail,
+/// but only with problems which can be reported, and never by panicking.
+/// Bytes which aren't UTF-8 are skipped, as inputs are read as text.
+pub fn parse_arbitrary<S: Solution>(data: &[u8]) {
+    let Ok(input) = str::from_utf8(data) else {
+        return;
+    };
+
+    if let Err(error) = S::parse(input) {
+        assert!(!error.problems.is_empty(), "parse failed with no problems");
+
+        // Problems point into the input, so must be reported without
+        // slicing outside it
+        let _ = error.to_string();
+    }
+}
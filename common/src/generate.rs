@@ -0,0 +1,72 @@
+use crate::Solution;
+
+/// A solution which can generate valid puzzle inputs of its own, for
+/// stress testing and benchmarking.
+pub trait Generate: Solution {
+    /// The size of a personal puzzle input, in the units `generate` takes.
+    const SIZE: usize;
+
+    /// Generate an input in the puzzle's text format, with `size` of
+    /// whatever the input is made of (lines, crabs, scanners, ...). Days
+    /// whose inputs have a fixed shape ignore `size`, and a `size` too
+    /// small to make an input the day accepts is raised to the smallest
+    /// which does.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// A xorshift64* generator, which is plenty for shuffling puzzle inputs,
+/// and gives the same inputs for the same seed on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number in low..=high.
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + (self.next_u64() % (high - low + 1) as u64) as i64
+    }
+
+    /// A number in 0..n.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for j in (1..items.len()).rev() {
+            items.swap(j, self.below(j + 1));
+        }
+    }
+
+    /// A map of `width` by `height` characters, one line per row.
+    pub fn char_map(
+        &mut self,
+        width: usize,
+        height: usize,
+        cell: impl Fn(&mut Rng) -> char,
+    ) -> String {
+        let mut result = String::new();
+
+        for _ in 0..height {
+            for _ in 0..width {
+                result.push(cell(self));
+            }
+            result.push('\n');
+        }
+
+        result
+    }
+}
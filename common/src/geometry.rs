@@ -0,0 +1,348 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// The signed integer types which `Vec3` and `Cuboid` are used with.
+pub trait Coordinate:
+    Copy
+    + Ord
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! coordinate {
+    ($($number:ty),*) => {
+        $(
+            impl Coordinate for $number {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(i32, i64);
+
+/// A point, or the offset between two points, in 3D space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+}
+
+impl<T: Coordinate> Vec3<T> {
+    fn to_array(self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    fn from_array([x, y, z]: [T; 3]) -> Vec3<T> {
+        Vec3::new(x, y, z)
+    }
+
+    pub fn abs(self) -> Vec3<T> {
+        Vec3::from_array(self.to_array().map(|c| if c < T::ZERO { -c } else { c }))
+    }
+
+    /// The distance along the axes, |x| + |y| + |z|.
+    pub fn manhattan(self) -> T {
+        let Vec3 { x, y, z } = self.abs();
+
+        x + y + z
+    }
+
+    /// The square of the straight line distance, which stays exact.
+    pub fn euclidean_squared(self) -> T {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    pub fn euclidean(self) -> f64 {
+        self.euclidean_squared().to_f64().sqrt()
+    }
+
+    /// The lower of each coordinate.
+    pub fn min(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// The higher of each coordinate.
+    pub fn max(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+
+impl<T: Coordinate> Add for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn add(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Coordinate> Sub for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn sub(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Coordinate> Neg for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn neg(self) -> Vec3<T> {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// One of the 24 ways of turning a cube so that its faces stay lined up
+/// with the axes: each coordinate of the result is a coordinate of the
+/// original, perhaps negated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation {
+    axes: [usize; 3],
+    negated: [bool; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        axes: [0, 1, 2],
+        negated: [false; 3],
+    };
+
+    /// Every rotation, starting with the identity.
+    pub fn all() -> Vec<Rotation> {
+        const PERMUTATIONS: [([usize; 3], bool); 6] = [
+            ([0, 1, 2], false),
+            ([0, 2, 1], true),
+            ([1, 0, 2], true),
+            ([1, 2, 0], false),
+            ([2, 0, 1], false),
+            ([2, 1, 0], true),
+        ];
+        let mut rotations = Vec::new();
+
+        for (axes, odd) in PERMUTATIONS {
+            for signs in 0..8 {
+                let negated = [signs & 1 != 0, signs & 2 != 0, signs & 4 != 0];
+
+                // Swapping two axes or negating one mirrors the cube, so an
+                // odd number of both would turn it inside out
+                let negations = negated.iter().filter(|&&negated| negated).count();
+                if odd == (negations % 2 == 0) {
+                    continue;
+                }
+
+                rotations.push(Rotation { axes, negated });
+            }
+        }
+
+        rotations
+    }
+
+    pub fn apply<T: Coordinate>(self, vector: Vec3<T>) -> Vec3<T> {
+        let coordinates = vector.to_array();
+
+        Vec3::from_array([0, 1, 2].map(|j| {
+            let coordinate = coordinates[self.axes[j]];
+            if self.negated[j] {
+                -coordinate
+            } else {
+                coordinate
+            }
+        }))
+    }
+
+    /// This rotation followed by `next`.
+    pub fn then(self, next: Rotation) -> Rotation {
+        Rotation {
+            axes: next.axes.map(|axis| self.axes[axis]),
+            negated: [0, 1, 2].map(|j| next.negated[j] != self.negated[next.axes[j]]),
+        }
+    }
+
+    pub fn inverse(self) -> Rotation {
+        let mut inverse = Rotation::IDENTITY;
+        for j in 0..3 {
+            inverse.axes[self.axes[j]] = j;
+            inverse.negated[self.axes[j]] = self.negated[j];
+        }
+
+        inverse
+    }
+}
+
+/// The integer points from `min` to `max` inclusive along every axis, an
+/// axis-aligned box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T> {
+    pub min: Vec3<T>,
+    pub max: Vec3<T>,
+}
+
+impl<T> Cuboid<T> {
+    pub const fn new(min: Vec3<T>, max: Vec3<T>) -> Cuboid<T> {
+        Cuboid { min, max }
+    }
+}
+
+impl<T: Coordinate> Cuboid<T> {
+    pub fn contains(&self, point: Vec3<T>) -> bool {
+        self.min.max(point) == point && self.max.min(point) == point
+    }
+
+    pub fn intersects(&self, other: &Cuboid<T>) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(&self, other: &Cuboid<T>) -> Option<Cuboid<T>> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+
+        if min.x > max.x || min.y > max.y || min.z > max.z {
+            None
+        } else {
+            Some(Cuboid { min, max })
+        }
+    }
+
+    /// The number of points in the cuboid, which must have `min` at or
+    /// below `max` along every axis and a volume that fits in `T`.
+    pub fn volume(&self) -> T {
+        let Vec3 { x, y, z } = self.max - self.min;
+
+        (x + T::ONE) * (y + T::ONE) * (z + T::ONE)
+    }
+
+    /// The points of this cuboid outside `other`, as at most six cuboids
+    /// which don't overlap.
+    pub fn difference(&self, other: &Cuboid<T>) -> Vec<Cuboid<T>> {
+        let Some(overlap) = self.intersection(other) else {
+            return vec![*self];
+        };
+        let mut pieces = Vec::new();
+
+        // Cut off the slabs either side of the overlap along each axis in
+        // turn, leaving just the overlap
+        let (mut min, mut max) = (self.min.to_array(), self.max.to_array());
+        let (overlap_min, overlap_max) = (overlap.min.to_array(), overlap.max.to_array());
+        for axis in 0..3 {
+            if min[axis] < overlap_min[axis] {
+                let mut slab_max = max;
+                slab_max[axis] = overlap_min[axis] - T::ONE;
+                pieces.push(Cuboid::new(
+                    Vec3::from_array(min),
+                    Vec3::from_array(slab_max),
+                ));
+                min[axis] = overlap_min[axis];
+            }
+            if max[axis] > overlap_max[axis] {
+                let mut slab_min = min;
+                slab_min[axis] = overlap_max[axis] + T::ONE;
+                pieces.push(Cuboid::new(
+                    Vec3::from_array(slab_min),
+                    Vec3::from_array(max),
+                ));
+                max[axis] = overlap_max[axis];
+            }
+        }
+
+        pieces
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Cuboid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "x={}..{},y={}..{},z={}..{}",
+            self.min.x, self.max.x, self.min.y, self.max.y, self.min.z, self.max.z
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn norms() {
+        let offset = Vec3::new(3, -4, 12) - Vec3::new(0, 0, 0);
+
+        assert_eq!(offset.manhattan(), 19);
+        assert_eq!(offset.euclidean_squared(), 169);
+        assert_eq!(offset.euclidean(), 13.0);
+        assert_eq!(-offset + offset, Vec3::default());
+    }
+
+    #[test]
+    fn rotations() {
+        let rotations = Rotation::all();
+        let point = Vec3::new(1, 2, 3);
+
+        let images: HashSet<Vec3<i32>> = rotations.iter().map(|r| r.apply(point)).collect();
+        assert_eq!(images.len(), 24);
+        assert_eq!(rotations[0], Rotation::IDENTITY);
+
+        for &rotation in rotations.iter() {
+            assert_eq!(rotation.then(rotation.inverse()), Rotation::IDENTITY);
+            for &next in rotations.iter() {
+                let turned = rotation.then(next).apply(point);
+                assert_eq!(turned, next.apply(rotation.apply(point)));
+            }
+        }
+    }
+
+    #[test]
+    fn cuboid_difference() {
+        let a = Cuboid::new(Vec3::new(0, 0, 0), Vec3::new(9, 9, 9));
+        let b = Cuboid::new(Vec3::new(5, -5, 2), Vec3::new(20, 5, 7));
+
+        let overlap = a.intersection(&b).unwrap();
+        assert_eq!(overlap.volume(), 5 * 6 * 6);
+
+        let pieces = a.difference(&b);
+        let volume: i64 = pieces.iter().map(|piece| piece.volume()).sum();
+        assert_eq!(volume, a.volume() - overlap.volume());
+        for (j, piece) in pieces.iter().enumerate() {
+            assert!(!piece.intersects(&b));
+            assert!(pieces[j + 1..].iter().all(|other| !piece.intersects(other)));
+        }
+
+        let far = Cuboid::new(Vec3::new(50, 50, 50), Vec3::new(60, 60, 60));
+        assert_eq!(a.difference(&far), vec![a]);
+        assert!(a.contains(Vec3::new(9, 0, 5)) && !a.contains(Vec3::new(10, 0, 5)));
+    }
+}
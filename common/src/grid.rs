@@ -0,0 +1,337 @@
+use crate::error::{parse_lines, ParseError, Problem};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// The neighbors of a cell: the four which share an edge with it, or the
+/// eight which share an edge or a corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    Four,
+    Eight,
+}
+
+impl Neighborhood {
+    // Offsets in reading order, so that neighbors are visited top to bottom
+    // and left to right
+    fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Neighborhood::Four => &[(0, -1), (-1, 0), (1, 0), (0, 1)],
+            Neighborhood::Eight => &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+/// A rectangular map of cells, such as a height map, stored row by row in
+/// one buffer. Cells are addressed by `(x, y)`, with `(0, 0)` at the top
+/// left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parse a map with one character per cell, such as "2199943210".
+    /// `cell` converts each character, and `expected` describes the
+    /// characters it accepts, for reporting any others.
+    pub fn parse<F>(input: &str, expected: &str, cell: F) -> Result<Grid<T>, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let rows = parse_lines(input, |line| {
+            let mut cells = Vec::new();
+            let mut problems = Vec::new();
+
+            for (x, ch) in line.chars().enumerate() {
+                match cell(ch) {
+                    Some(value) => cells.push(value),
+                    None => problems.push(Problem::new(
+                        1,
+                        x + 1,
+                        line,
+                        &ch.to_string(),
+                        format!("expected {}, found {:?}", expected, ch),
+                    )),
+                }
+            }
+
+            if problems.is_empty() {
+                Ok(cells)
+            } else {
+                Err(ParseError::new(problems))
+            }
+        })?;
+
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err(Problem::at_end(input, "expected a map").into());
+        }
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            let line = input.lines().nth(y).unwrap();
+            return Err(Problem::new(
+                y + 1,
+                1,
+                line,
+                line,
+                format!("expected a row of {} cells", width),
+            )
+            .into());
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Every position, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+
+        (0..self.width * self.height).map(move |j| (j % width, j / width))
+    }
+
+    /// Every position with its cell, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every row, top to bottom. A grid with no columns still has its
+    /// rows, each of them empty.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// The cells of column `x`, top to bottom, or none if there is no such
+    /// column.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = match self.cells.get(x..) {
+            Some(cells) if x < self.width => cells,
+            _ => &[],
+        };
+
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// The position one step of `(dx, dy)` from `(x, y)`, if it is on the
+    /// grid.
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+
+        Some((x, y))
+    }
+
+    /// The position one step of `(dx, dy)` from `(x, y)`, wrapping around
+    /// the edges as though the grid were a torus.
+    pub fn wrapping_step(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> (usize, usize) {
+        let wrap = |value: usize, delta: isize, size: usize| {
+            (value as isize + delta).rem_euclid(size as isize) as usize
+        };
+
+        (wrap(x, dx, self.width), wrap(y, dy, self.height))
+    }
+
+    /// The neighbors of `(x, y)` which are on the grid. The iterator does
+    /// not borrow the grid, so cells may be updated while visiting them.
+    pub fn neighbors(
+        &self,
+        position: (usize, usize),
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let bounds = Grid {
+            width: self.width,
+            height: self.height,
+            cells: Vec::<()>::new(),
+        };
+
+        neighborhood
+            .offsets()
+            .iter()
+            .filter_map(move |&offset| bounds.step(position, offset))
+    }
+
+    /// The neighbors of `(x, y)`, wrapping around the edges.
+    pub fn wrapping_neighbors(
+        &self,
+        position: (usize, usize),
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let bounds = Grid {
+            width: self.width,
+            height: self.height,
+            cells: Vec::<()>::new(),
+        };
+
+        neighborhood
+            .offsets()
+            .iter()
+            .map(move |&offset| bounds.wrapping_step(position, offset))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is off the grid",
+            x,
+            y
+        );
+
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is off the grid",
+            x,
+            y
+        );
+
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row.iter() {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, "a digit", |ch| ch.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = digits("123\n456\n");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn empty_grids() {
+        let grid = Grid::new(0, 3, 0);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[] as &[i32]; 3]);
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.columns().count(), 0);
+
+        let grid = Grid::new(2, 0, 0);
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.column(1).count(), 0);
+    }
+
+    #[test]
+    fn parse_reports_bad_cells_and_ragged_rows() {
+        let error = Grid::parse("12\n3x\n", "a digit", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!((error.problems[0].line, error.problems[0].column), (2, 2));
+
+        let error = Grid::parse("12\n3\n", "a digit", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!(error.problems[0].line, 2);
+    }
+
+    #[test]
+    fn neighbors_at_a_corner() {
+        let grid = digits("123\n456\n789\n");
+
+        let four: Vec<_> = grid.neighbors((0, 0), Neighborhood::Four).collect();
+        assert_eq!(four, vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors((0, 0), Neighborhood::Eight).count(), 3);
+        assert_eq!(grid.neighbors((1, 1), Neighborhood::Eight).count(), 8);
+
+        let wrapped: Vec<_> = grid
+            .wrapping_neighbors((0, 0), Neighborhood::Four)
+            .collect();
+        assert_eq!(wrapped, vec![(0, 2), (2, 0), (1, 0), (0, 1)]);
+    }
+}
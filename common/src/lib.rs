@@ -0,0 +1,40 @@
+mod answer;
+mod capture;
+mod error;
+mod fuzz;
+mod generate;
+mod geometry;
+mod grid;
+mod search;
+mod stream;
+mod visual;
+
+pub use answer::{Answer, NoAnswer};
+pub use capture::{capture, Capture};
+pub use error::{parse_lines, parse_separated, parse_span, parse_text, ParseError, Problem};
+pub use fuzz::parse_arbitrary;
+pub use generate::{Generate, Rng};
+pub use geometry::{Coordinate, Cuboid, Rotation, Vec3};
+pub use grid::{Grid, Neighborhood};
+pub use search::{astar, bfs, dijkstra, Path, Search};
+pub use stream::{parse_stream, parse_streamed, ReadError, Streaming};
+pub use visual::{replay, write_images, Palette, Recording, Simulation};
+
+/// A solver for one day of the puzzle.
+///
+/// The puzzle input is parsed once into `Self`, and each part is then
+/// answered from the parsed value without consuming it, so that both
+/// parts can be run (in either order) against the same parse. Problems
+/// with the text of the input are reported by `parse`, rather than by
+/// panicking, and inputs which parse but turn out to have no answer give
+/// a `NoAnswer` from the part.
+pub trait Solution: Sized {
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+
+    fn part_1(&self) -> Self::Part1;
+
+    fn part_2(&self) -> Self::Part2;
+}
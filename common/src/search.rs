@@ -0,0 +1,195 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A search problem: the states reachable from a start, the cost of each
+/// step between them, and which of them are goals.
+pub trait Search {
+    type State: Clone + Eq + Hash;
+
+    /// Each state one step from `state`, with the cost of that step.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, u32)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A lower bound on the cost from `state` to the nearest goal, which
+    /// guides `astar`. It must never overestimate, or the path found may
+    /// not be the cheapest.
+    fn heuristic(&self, _state: &Self::State) -> u32 {
+        0
+    }
+}
+
+/// The cheapest path found to a goal, from the start to the goal
+/// inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u32,
+    pub states: Vec<S>,
+}
+
+// Every state seen, by index, with the cheapest cost found to it so far
+// and the state it was reached from
+struct Visited<S> {
+    indexes: HashMap<S, usize>,
+    states: Vec<S>,
+    costs: Vec<u32>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new(start: S) -> Visited<S> {
+        Visited {
+            indexes: HashMap::from([(start.clone(), 0)]),
+            states: vec![start],
+            costs: vec![0],
+            parents: vec![None],
+        }
+    }
+
+    // Record reaching `state` from `parent` with `cost`, returning its
+    // index if that is cheaper than any way of reaching it so far
+    fn reach(&mut self, state: S, cost: u32, parent: usize) -> Option<usize> {
+        match self.indexes.get(&state) {
+            Some(&index) if self.costs[index] <= cost => None,
+            Some(&index) => {
+                self.costs[index] = cost;
+                self.parents[index] = Some(parent);
+                Some(index)
+            }
+            None => {
+                let index = self.states.len();
+                self.indexes.insert(state.clone(), index);
+                self.states.push(state);
+                self.costs.push(cost);
+                self.parents.push(Some(parent));
+                Some(index)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<S> {
+        let mut states = Vec::new();
+        let mut index = Some(goal);
+        while let Some(j) = index {
+            states.push(self.states[j].clone());
+            index = self.parents[j];
+        }
+        states.reverse();
+
+        Path {
+            cost: self.costs[goal],
+            states,
+        }
+    }
+}
+
+fn best_first<P, F>(problem: &P, start: P::State, estimate: F) -> Option<Path<P::State>>
+where
+    P: Search,
+    F: Fn(&P::State) -> u32,
+{
+    let mut visited = Visited::new(start);
+    let mut candidates = BinaryHeap::from([Reverse((estimate(&visited.states[0]), 0, 0))]);
+
+    while let Some(Reverse((_estimate, cost, index))) = candidates.pop() {
+        // A cheaper way to this state was found after this one was queued
+        if cost > visited.costs[index] {
+            continue;
+        }
+
+        let state = &visited.states[index];
+        if problem.is_goal(state) {
+            return Some(visited.path(index));
+        }
+
+        for (successor, step_cost) in problem.successors(state) {
+            let successor_cost = cost + step_cost;
+            let successor_estimate = successor_cost + estimate(&successor);
+            if let Some(successor_index) = visited.reach(successor, successor_cost, index) {
+                candidates.push(Reverse((
+                    successor_estimate,
+                    successor_cost,
+                    successor_index,
+                )));
+            }
+        }
+    }
+
+    None
+}
+
+/// The cheapest path from `start` to a goal, ignoring the heuristic.
+pub fn dijkstra<P: Search>(problem: &P, start: P::State) -> Option<Path<P::State>> {
+    best_first(problem, start, |_| 0)
+}
+
+/// The cheapest path from `start` to a goal, searching the states which
+/// the heuristic estimates are closest to a goal first.
+pub fn astar<P: Search>(problem: &P, start: P::State) -> Option<Path<P::State>> {
+    best_first(problem, start, |state| problem.heuristic(state))
+}
+
+/// The path from `start` to a goal with the fewest steps, ignoring the
+/// cost of each step. Its cost is the number of steps.
+pub fn bfs<P: Search>(problem: &P, start: P::State) -> Option<Path<P::State>> {
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        let state = &visited.states[index];
+        if problem.is_goal(state) {
+            return Some(visited.path(index));
+        }
+
+        let steps = visited.costs[index] + 1;
+        for (successor, _) in problem.successors(state) {
+            if let Some(successor_index) = visited.reach(successor, steps, index) {
+                queue.push_back(successor_index);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A line of stops from 0 to 10, where each step forward costs 1 and
+    // jumping ahead five costs 10
+    struct Line;
+
+    impl Search for Line {
+        type State = u32;
+
+        fn successors(&self, &stop: &u32) -> Vec<(u32, u32)> {
+            [(stop + 1, 1), (stop + 5, 10)]
+                .into_iter()
+                .filter(|&(next, _)| next <= 10)
+                .collect()
+        }
+
+        fn is_goal(&self, &stop: &u32) -> bool {
+            stop == 10
+        }
+
+        fn heuristic(&self, &stop: &u32) -> u32 {
+            10 - stop
+        }
+    }
+
+    #[test]
+    fn cheapest_and_fewest_steps() {
+        let path = dijkstra(&Line, 0).unwrap();
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.states, (0..=10).collect::<Vec<_>>());
+
+        assert_eq!(astar(&Line, 0), Some(path));
+
+        let path = bfs(&Line, 0).unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.states, vec![0, 5, 10]);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{ParseError, Solution};
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// A solution which can be parsed a line at a time, folding each line into
+/// what it keeps as it is read, so that inputs too big to hold in memory
+/// can still be solved. Parsing starts from the default value.
+pub trait Streaming: Solution + Default {
+    /// Fold one line in, reporting problems as if the line were the first
+    /// line of the input.
+    fn parse_line(&mut self, line: &str) -> Result<(), ParseError>;
+
+    /// Check what was read once all `lines` lines have been folded in.
+    fn finish(self, _lines: usize) -> Result<Self, ParseError> {
+        Ok(self)
+    }
+}
+
+/// A failure to read a puzzle input, or a problem with what was read.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl From<io::Error> for ReadError {
+    fn from(error: io::Error) -> Self {
+        ReadError::Io(error)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(error: ParseError) -> Self {
+        ReadError::Parse(error)
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(error) => writeln!(f, "error: {}", error),
+            ReadError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+fn fold_lines<S, L, I>(lines: I) -> Result<S, ReadError>
+where
+    S: Streaming,
+    L: AsRef<str>,
+    I: Iterator<Item = io::Result<L>>,
+{
+    let mut solution = S::default();
+    let mut problems = Vec::new();
+    let mut count = 0;
+
+    for line in lines {
+        let line = line?;
+        if let Err(error) = solution.parse_line(line.as_ref()) {
+            problems.extend(error.problems.into_iter().map(|mut problem| {
+                problem.line += count;
+                problem
+            }));
+        }
+        count += 1;
+    }
+
+    if !problems.is_empty() {
+        return Err(ParseError::new(problems).into());
+    }
+
+    Ok(solution.finish(count)?)
+}
+
+/// Parse lines as they are read, such as from `BufRead::lines`, collecting
+/// the problems with every line but stopping at the first I/O error.
+pub fn parse_stream<S: Streaming>(
+    lines: impl Iterator<Item = io::Result<String>>,
+) -> Result<S, ReadError> {
+    fold_lines(lines)
+}
+
+/// Parse an input already in memory a line at a time, for implementing
+/// `Solution::parse` with the same code which parses streams.
+pub fn parse_streamed<S: Streaming>(input: &str) -> Result<S, ParseError> {
+    match fold_lines(input.lines().map(io::Result::Ok)) {
+        Ok(solution) => Ok(solution),
+        Err(ReadError::Parse(error)) => Err(error),
+        Err(ReadError::Io(_)) => unreachable!("no I/O reading a string"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_text;
+
+    #[derive(Debug, Default)]
+    struct Sum(u32);
+
+    impl Solution for Sum {
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> Result<Sum, ParseError> {
+            parse_streamed(input)
+        }
+
+        fn part_1(&self) -> u32 {
+            self.0
+        }
+
+        fn part_2(&self) -> u32 {
+            self.0
+        }
+    }
+
+    impl Streaming for Sum {
+        fn parse_line(&mut self, line: &str) -> Result<(), ParseError> {
+            self.0 += parse_text::<u32>(line, 1, line)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn problems_on_their_own_lines() {
+        let error = Sum::parse("1\nx\n3\ny\n").unwrap_err();
+        let lines: Vec<usize> = error.problems.iter().map(|problem| problem.line).collect();
+
+        assert_eq!(lines, vec![2, 4]);
+        assert_eq!(Sum::parse("1\n2\n3\n").unwrap().part_1(), 6);
+    }
+
+    #[test]
+    fn stops_at_io_error() {
+        let lines = vec![
+            Ok(String::from("1")),
+            Err(io::Error::new(io::ErrorKind::InvalidData, "bad bytes")),
+            Ok(String::from("x")),
+        ];
+
+        match parse_stream::<Sum>(lines.into_iter()) {
+            Err(ReadError::Io(error)) => assert_eq!(error.kind(), io::ErrorKind::InvalidData),
+            result => panic!("expected an I/O error, got {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{Grid, Solution};
+use std::borrow::Cow;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+/// A solution whose simulation can be recorded step by step, for
+/// rendering as images or a terminal replay.
+pub trait Simulation: Solution {
+    fn record(&self) -> Recording;
+}
+
+/// Every step of a simulation, each as a grid of levels from 0 up to
+/// `levels - 1`, which a palette turns into colors. Frames need not all
+/// be the same size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recording {
+    pub levels: u8,
+    pub frames: Vec<Grid<u8>>,
+}
+
+impl Recording {
+    pub fn new(levels: u8) -> Recording {
+        Recording {
+            levels,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, frame: Grid<u8>) {
+        self.frames.push(frame);
+    }
+}
+
+/// The colors which levels are drawn in, spread evenly from the first
+/// color for level 0 to the last color for the highest level.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<[u8; 3]>,
+}
+
+impl Palette {
+    /// Parse a named palette, "gray" or "heat", or a comma separated list
+    /// of at least two colors, such as "000000,ff8800,ffffff".
+    pub fn parse(text: &str) -> Result<Palette, String> {
+        let colors = match text {
+            "gray" => vec![[0, 0, 0], [255, 255, 255]],
+            "heat" => vec![[0, 0, 0], [192, 0, 0], [255, 192, 0], [255, 255, 255]],
+            _ => text
+                .split(',')
+                .map(|hex| {
+                    let hex = hex.trim_start_matches('#');
+                    // Checked first, as from_str_radix takes a leading sign
+                    if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                        return Err(format!("invalid color {:?}, expected rrggbb", hex));
+                    }
+                    let value = u32::from_str_radix(hex, 16).unwrap();
+                    Ok([(value >> 16) as u8, (value >> 8) as u8, value as u8])
+                })
+                .collect::<Result<Vec<[u8; 3]>, String>>()?,
+        };
+
+        if colors.len() < 2 {
+            return Err(format!("invalid palette {:?}, expected two colors", text));
+        }
+
+        Ok(Palette { colors })
+    }
+
+    fn is_gray(&self) -> bool {
+        self.colors.iter().all(|&[r, g, b]| r == g && g == b)
+    }
+
+    fn color(&self, level: u8, levels: u8) -> [u8; 3] {
+        if levels < 2 {
+            return self.colors[0];
+        }
+
+        // Position of the level along the palette, in units of
+        // 1 / (levels - 1) of the gap between two colors
+        let steps = usize::from(levels - 1);
+        let position = usize::from(level.min(levels - 1)) * (self.colors.len() - 1);
+        let (j, remainder) = (position / steps, position % steps);
+        if remainder == 0 {
+            return self.colors[j];
+        }
+
+        let (from, to) = (self.colors[j], self.colors[j + 1]);
+        let mut color = [0; 3];
+        for k in 0..3 {
+            let (from, to) = (from[k] as usize, to[k] as usize);
+            color[k] = ((from * (steps - remainder) + to * remainder) / steps) as u8;
+        }
+
+        color
+    }
+}
+
+// A frame with no cells, such as paper folded down to nothing, is drawn
+// as a single cell of level 0, since images can't be empty
+fn padded(frame: &Grid<u8>) -> Cow<'_, Grid<u8>> {
+    if frame.width() > 0 && frame.height() > 0 {
+        return Cow::Borrowed(frame);
+    }
+
+    Cow::Owned(Grid::new(frame.width().max(1), frame.height().max(1), 0))
+}
+
+// A binary PGM if the palette is gray, otherwise a binary PPM, with each
+// cell drawn as a square of scale by scale pixels
+fn image(frame: &Grid<u8>, levels: u8, scale: usize, palette: &Palette) -> Vec<u8> {
+    let frame = padded(frame);
+    let gray = palette.is_gray();
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    let mut image = format!(
+        "{}\n{} {}\n255\n",
+        if gray { "P5" } else { "P6" },
+        width,
+        height
+    )
+    .into_bytes();
+
+    for row in frame.rows() {
+        let mut pixels = Vec::new();
+        for &level in row.iter() {
+            let color = palette.color(level, levels);
+            for _ in 0..scale {
+                pixels.extend_from_slice(if gray { &color[..1] } else { &color });
+            }
+        }
+        for _ in 0..scale {
+            image.extend_from_slice(&pixels);
+        }
+    }
+
+    image
+}
+
+/// Write each frame to `directory` as `<name>-NNNN.pgm` or `.ppm`,
+/// returning the paths written.
+pub fn write_images(
+    recording: &Recording,
+    directory: &Path,
+    name: &str,
+    scale: usize,
+    palette: &Palette,
+) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(directory)?;
+
+    let extension = if palette.is_gray() { "pgm" } else { "ppm" };
+    let mut paths = Vec::new();
+
+    for (j, frame) in recording.frames.iter().enumerate() {
+        let path = directory.join(format!("{}-{:04}.{}", name, j, extension));
+        fs::write(&path, image(frame, recording.levels, scale, palette))?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+/// Replay the frames in a terminal with 24-bit color, drawing each cell as
+/// a block `2 * scale` columns wide and `scale` lines high, and pausing
+/// `delay` between frames.
+pub fn replay(
+    recording: &Recording,
+    out: &mut impl Write,
+    scale: usize,
+    palette: &Palette,
+    delay: Duration,
+) -> io::Result<()> {
+    let cell = "  ".repeat(scale);
+
+    for (j, frame) in recording.frames.iter().enumerate() {
+        // Move to the top left and clear the screen
+        let mut text = String::from("\x1b[H\x1b[2J");
+
+        for row in padded(frame).rows() {
+            let mut line = String::new();
+            for &level in row.iter() {
+                let [r, g, b] = palette.color(level, recording.levels);
+                line.push_str(&format!("\x1b[48;2;{};{};{}m{}", r, g, b, cell));
+            }
+            line.push_str("\x1b[0m\n");
+            for _ in 0..scale {
+                text.push_str(&line);
+            }
+        }
+        text.push_str(&format!("Step {} of {}\n", j, recording.frames.len() - 1));
+
+        out.write_all(text.as_bytes())?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette_spreads_levels_over_colors() {
+        let palette = Palette::parse("000000,ff0000,ffffff").unwrap();
+
+        assert_eq!(palette.color(0, 5), [0, 0, 0]);
+        assert_eq!(palette.color(1, 5), [127, 0, 0]);
+        assert_eq!(palette.color(2, 5), [255, 0, 0]);
+        assert_eq!(palette.color(4, 5), [255, 255, 255]);
+
+        assert!(Palette::parse("ffffff").is_err());
+        assert!(Palette::parse("fff,000").is_err());
+        assert!(Palette::parse("+fffff,000000").is_err());
+    }
+
+    #[test]
+    fn scaled_pgm() {
+        let frame = Grid::parse("#.", "'#' or '.'", |ch| Some((ch == '#') as u8)).unwrap();
+        let palette = Palette::parse("gray").unwrap();
+
+        let mut expected = b"P5\n4 2\n255\n".to_vec();
+        expected.extend([255, 255, 0, 0, 255, 255, 0, 0]);
+        assert_eq!(image(&frame, 2, 2, &palette), expected);
+    }
+
+    #[test]
+    fn empty_frames_are_padded() {
+        let palette = Palette::parse("gray").unwrap();
+        assert_eq!(
+            image(&Grid::new(0, 2, 1), 2, 1, &palette),
+            b"P5\n1 2\n255\n\0\0"
+        );
+
+        let recording = Recording {
+            levels: 2,
+            frames: vec![Grid::new(3, 0, 1)],
+        };
+        let mut out = Vec::new();
+        replay(&recording, &mut out, 1, &palette, Duration::ZERO).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().matches('\n').count(), 2);
+    }
+}
@@ -0,0 +1,20 @@
+use crate::Day01;
+use common::{Generate, Rng};
+use std::fmt::Write;
+
+impl Generate for Day01 {
+    // Depth measurements
+    const SIZE: usize = 2000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut depth = 150;
+        let mut result = String::new();
+
+        for _ in 0..size {
+            depth = (depth + rng.range(-20, 30)).max(100);
+            writeln!(result, "{}", depth).unwrap();
+        }
+
+        result
+    }
+}
@@ -0,0 +1,99 @@
+mod generate;
+mod report;
+mod window;
+
+use common::{parse_streamed, parse_text, ParseError, Problem, Solution, Streaming};
+pub use report::{Fall, Report, Run, Segment, Trend};
+pub use window::{
+    count_increases, Increases, LiveIncreases, Window, WindowSums, WindowSumsExt, MAX_WINDOW,
+};
+
+/// Parse one line of a sonar sweep, for reading a feed of depths which
+/// never ends a line at a time.
+pub fn parse_depth(line: &str) -> Result<u32, Problem> {
+    let column = line.len() - line.trim_start().len() + 1;
+
+    parse_text(line, column, line.trim())
+}
+
+#[derive(Debug, Default)]
+pub struct Day01 {
+    depths: Vec<u32>,
+}
+
+impl Solution for Day01 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Day01, ParseError> {
+        parse_streamed(input)
+    }
+
+    fn part_1(&self) -> u64 {
+        self.increases(1, 1)
+    }
+
+    fn part_2(&self) -> u64 {
+        self.increases(3, 1)
+    }
+}
+
+impl Day01 {
+    /// Count the sums of `window` consecutive depths which are larger than
+    /// the sum `step` windows before. Panics if either is zero, or `window`
+    /// is more than `MAX_WINDOW`.
+    pub fn increases(&self, window: usize, step: usize) -> u64 {
+        count_increases(self.depths.iter().copied().window_sums(window), step)
+    }
+
+    /// Analyse the sweep, judging trends over `window` measurements, with
+    /// a chart `width` columns wide.
+    pub fn report(&self, window: usize, width: usize) -> Report {
+        Report::new(&self.depths, window, width)
+    }
+}
+
+impl Streaming for Day01 {
+    fn parse_line(&mut self, line: &str) -> Result<(), ParseError> {
+        self.depths.push(parse_depth(line)?);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Generate, Rng};
+    use proptest::prelude::*;
+
+    // Add up every window afresh and compare it with the one `step` before
+    fn brute_force(input: &str, window: usize, step: usize) -> u64 {
+        let depths: Vec<u64> = input.lines().map(|line| line.parse().unwrap()).collect();
+        let sums: Vec<u64> = depths
+            .windows(window)
+            .map(|depths| depths.iter().sum())
+            .collect();
+
+        (step..sums.len())
+            .filter(|&j| sums[j] > sums[j - step])
+            .count() as u64
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(
+            seed in any::<u64>(),
+            size in 1..200usize,
+            window in 1..10usize,
+            step in 1..10usize,
+        ) {
+            let input = Day01::generate(&mut Rng::new(seed), size);
+            let day = Day01::parse(&input).unwrap();
+
+            prop_assert_eq!(day.part_1(), brute_force(&input, 1, 1));
+            prop_assert_eq!(day.part_2(), brute_force(&input, 3, 1));
+            prop_assert_eq!(day.increases(window, step), brute_force(&input, window, step));
+        }
+    }
+}
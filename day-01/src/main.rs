@@ -1,48 +1,167 @@
-use std::io::{stdin, Read};
+use common::{parse_stream, ParseError, Solution};
+use day_01::{parse_depth, Day01, LiveIncreases, MAX_WINDOW};
+use std::env;
+use std::io::{self, stdin, BufRead, Write};
+use std::process;
 
-fn main() {
-    let mut input = String::new();
-    stdin().read_to_string(&mut input).unwrap();
+const USAGE: &str = "\
+Usage: day-01 [--window <WINDOW>] [--step <STEP>] < INPUT
+       day-01 --report [--window <WINDOW>] < INPUT
+       day-01 --live [--window <WINDOW>] [--step <STEP>] < FEED
 
-    let depths: Vec<u32> = input
-        .lines()
-        .map(|x| x.trim().parse::<u32>().unwrap())
-        .collect();
+Options:
+  --window <WINDOW>  also count the sums of WINDOW consecutive measurements
+                     which are larger than an earlier sum (default: 3)
+  --step <STEP>      compare each sum with the sum STEP windows before
+                     (default: 1)
+  --report           instead of the answers, report on the seabed: the
+                     longest runs, plateaus and drops, and a chart of the
+                     sweep split into trends, each judged over WINDOW
+                     measurements
+  --live             read depths a line at a time as they arrive, such as
+                     from a pipe, and after each one print the counts so
+                     far for part 1 and for the window; lines which aren't
+                     depths are reported and skipped";
 
-    // Part 1
+// The width of the report's chart
+const CHART_WIDTH: usize = 72;
+
+#[derive(Debug, Default)]
+struct Args {
+    window: Option<usize>,
+    step: Option<usize>,
+    report: bool,
+    live: bool,
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args::default();
 
-    let mut last_depth_opt = None;
-    let mut increases = 0;
-    for &depth in depths.iter() {
-        if let Some(last_depth) = last_depth_opt {
-            if depth > last_depth {
-                increases += 1;
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        let target = match arg.as_str() {
+            "--window" => &mut parsed.window,
+            "--step" => &mut parsed.step,
+            "--report" => {
+                parsed.report = true;
+                continue;
+            }
+            "--live" => {
+                parsed.live = true;
+                continue;
+            }
+            _ => return Err(format!("unknown argument {}", arg)),
+        };
+        let text = args
+            .next()
+            .ok_or_else(|| format!("{} requires a value", arg))?;
+        *target = match text.parse::<usize>() {
+            Ok(value) if value > 0 && (arg != "--window" || value <= MAX_WINDOW) => Some(value),
+            _ => return Err(format!("invalid value {:?} for {}", text, arg)),
+        };
+    }
+
+    if parsed.report && parsed.step.is_some() {
+        return Err(String::from("--step cannot be used with --report"));
+    }
+    if parsed.report && parsed.live {
+        return Err(String::from("--report cannot be used with --live"));
+    }
+
+    Ok(parsed)
+}
+
+// Keep only the state of the windows, so that the feed can go on forever
+fn live(window: usize, step: usize) -> io::Result<()> {
+    let mut single = LiveIncreases::new(1, 1);
+    let mut windowed = LiveIncreases::new(window, step);
+    let mut out = io::stdout().lock();
+
+    for (j, line) in stdin().lock().lines().enumerate() {
+        let line = line?;
+        let depth = match parse_depth(&line) {
+            Ok(depth) => depth,
+            Err(mut problem) => {
+                problem.line = j + 1;
+                eprint!("{}", ParseError::from(problem));
+                continue;
+            }
+        };
+
+        let (single, windowed) = (single.push(depth), windowed.push(depth));
+
+        // Flush each line, so that whatever reads the output sees it at once
+        writeln!(
+            out,
+            "Measurement {}: depth {}, {} increases, {} window increases",
+            j + 1,
+            depth,
+            single,
+            windowed
+        )?;
+        out.flush()?;
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args = parse_args(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("error: {}\n\n{}", e, USAGE);
+        process::exit(2);
+    });
+
+    if args.live {
+        let (window, step) = (args.window.unwrap_or(3), args.step.unwrap_or(1));
+        match live(window, step) {
+            Ok(()) => {}
+            // Whatever reads the output has stopped
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {}
+            Err(e) => {
+                eprintln!("error: {}", e);
+                process::exit(1);
             }
         }
-        last_depth_opt = Some(depth);
+        return;
     }
 
+    let solution: Day01 = parse_stream(stdin().lock().lines()).unwrap_or_else(|e| {
+        eprint!("{}", e);
+        process::exit(1);
+    });
+
+    if args.report {
+        print!("{}", solution.report(args.window.unwrap_or(3), CHART_WIDTH));
+        return;
+    }
+
+    // Part 1
+
     println!(
         "Part 1: There are {} measurements larger than the previous measurement",
-        increases
+        solution.part_1()
     );
 
     // Part 2
 
-    let mut last_sum_opt = None;
-    let mut increases = 0;
-    for j in 2..depths.len() {
-        let sum = depths[j - 2] + depths[j - 1] + depths[j];
-        if let Some(last_sum) = last_sum_opt {
-            if sum > last_sum {
-                increases += 1;
-            }
-        }
-        last_sum_opt = Some(sum);
-    }
-
     println!(
         "Part 2: {} sums are larger than the previous sum",
-        increases
+        solution.part_2()
     );
+
+    // Any other window
+
+    if args.window.is_some() || args.step.is_some() {
+        let (window, step) = (args.window.unwrap_or(3), args.step.unwrap_or(1));
+        println!(
+            "Window of {}: {} sums are larger than {}",
+            window,
+            solution.increases(window, step),
+            if step == 1 {
+                String::from("the previous sum")
+            } else {
+                format!("the sum {} windows before", step)
+            }
+        );
+    }
 }
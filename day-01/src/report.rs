@@ -0,0 +1,342 @@
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+
+// Eighths of a block, from lowest to highest
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+// The number of largest drops reported
+const DROPS: usize = 3;
+
+/// Consecutive measurements, from `start` to `end` inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Run {
+    pub fn count(&self) -> usize {
+        self.end - self.start + 1
+    }
+}
+
+/// A fall in depth from one measurement to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fall {
+    pub index: usize,
+    pub from: u32,
+    pub to: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Deepening,
+    Shallowing,
+    Level,
+}
+
+impl Trend {
+    fn between(earlier: u32, later: u32) -> Trend {
+        match later.cmp(&earlier) {
+            Ordering::Greater => Trend::Deepening,
+            Ordering::Less => Trend::Shallowing,
+            Ordering::Equal => Trend::Level,
+        }
+    }
+
+    fn symbol(self) -> char {
+        match self {
+            Trend::Deepening => '+',
+            Trend::Shallowing => '-',
+            Trend::Level => '=',
+        }
+    }
+}
+
+/// A stretch of the sweep over which the seabed keeps to one trend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub run: Run,
+    pub trend: Trend,
+}
+
+/// What the depths of a sonar sweep say about the seabed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub depths: Vec<u32>,
+    pub longest_increase: Option<Run>,
+    pub longest_decrease: Option<Run>,
+    pub longest_plateau: Option<Run>,
+    /// The largest drops, largest first.
+    pub drops: Vec<Fall>,
+    pub segments: Vec<Segment>,
+    width: usize,
+}
+
+// The longest run of consecutive measurements each related to the one
+// before by `ordering`, if there are any two which are
+fn longest_run(depths: &[u32], ordering: Ordering) -> Option<Run> {
+    let mut longest: Option<Run> = None;
+    let mut start = 0;
+
+    for j in 1..depths.len() {
+        if depths[j].cmp(&depths[j - 1]) != ordering {
+            start = j;
+            continue;
+        }
+        let run = Run { start, end: j };
+        if longest.is_none_or(|longest| run.count() > longest.count()) {
+            longest = Some(run);
+        }
+    }
+
+    longest
+}
+
+// Split the sweep where the trend changes, judging the trend at each
+// measurement by comparing it with the measurement `window` before, as the
+// window sums do. Segments shorter than the window are noise, and are
+// merged into the segment before them.
+fn segments(depths: &[u32], window: usize) -> Vec<Segment> {
+    if depths.is_empty() {
+        return Vec::new();
+    }
+    if depths.len() <= window {
+        let trend = Trend::between(depths[0], depths[depths.len() - 1]);
+        let run = Run {
+            start: 0,
+            end: depths.len() - 1,
+        };
+        return vec![Segment { run, trend }];
+    }
+
+    let trends: Vec<Trend> = (0..depths.len())
+        .map(|j| {
+            let j = j.max(window);
+            Trend::between(depths[j - window], depths[j])
+        })
+        .collect();
+
+    let mut segments: Vec<Segment> = Vec::new();
+    for (j, &trend) in trends.iter().enumerate() {
+        match segments.last_mut() {
+            Some(segment) if segment.trend == trend => segment.run.end = j,
+            _ => segments.push(Segment {
+                run: Run { start: j, end: j },
+                trend,
+            }),
+        }
+    }
+
+    let mut merged: Vec<Segment> = Vec::new();
+    for segment in segments {
+        match merged.last_mut() {
+            Some(last) if segment.run.count() < window || last.trend == segment.trend => {
+                last.run.end = segment.run.end;
+            }
+            // A short first segment takes the trend of the one after it
+            Some(last) if last.run.count() < window => {
+                last.run.end = segment.run.end;
+                last.trend = segment.trend;
+            }
+            _ => merged.push(segment),
+        }
+    }
+
+    merged
+}
+
+impl Report {
+    /// Analyse the depths, judging trends over `window` measurements, for
+    /// a chart `width` columns wide. Panics if `window` or `width` is zero.
+    pub fn new(depths: &[u32], window: usize, width: usize) -> Report {
+        assert!(window > 0 && width > 0);
+
+        let mut drops: Vec<Fall> = (1..depths.len())
+            .filter(|&j| depths[j] < depths[j - 1])
+            .map(|j| Fall {
+                index: j,
+                from: depths[j - 1],
+                to: depths[j],
+            })
+            .collect();
+        drops.sort_by_key(|drop| (Reverse(drop.from - drop.to), drop.index));
+        drops.truncate(DROPS);
+
+        Report {
+            depths: depths.to_vec(),
+            longest_increase: longest_run(depths, Ordering::Greater),
+            longest_decrease: longest_run(depths, Ordering::Less),
+            longest_plateau: longest_run(depths, Ordering::Equal),
+            drops,
+            segments: segments(depths, window),
+            width,
+        }
+    }
+
+    // The measurements drawn in each column of the chart, as many to a
+    // column as it takes to fit the sweep into the width
+    fn columns(&self) -> Vec<Run> {
+        let count = self.depths.len();
+        let columns = count.min(self.width);
+
+        (0..columns)
+            .map(|column| Run {
+                start: column * count / columns,
+                end: (column + 1) * count / columns - 1,
+            })
+            .collect()
+    }
+
+    fn sparkline(&self, columns: &[Run]) -> String {
+        let min = self.depths.iter().min().copied().unwrap_or(0);
+        let max = self.depths.iter().max().copied().unwrap_or(0);
+        let range = u64::from(max - min).max(1);
+
+        columns
+            .iter()
+            .map(|run| {
+                let depths = &self.depths[run.start..=run.end];
+                let sum: u64 = depths.iter().map(|&depth| u64::from(depth)).sum();
+                let mean = sum / depths.len() as u64;
+                let level = (mean - u64::from(min)) * (BARS.len() as u64 - 1) / range;
+                BARS[level as usize]
+            })
+            .collect()
+    }
+
+    // The trend under each column, with a bar where a segment starts
+    fn annotations(&self, columns: &[Run]) -> String {
+        let mut segment = 0;
+
+        columns
+            .iter()
+            .map(|run| {
+                let starts = self.segments[segment..]
+                    .iter()
+                    .skip(1)
+                    .take_while(|next| next.run.start <= run.end)
+                    .count();
+                segment += starts;
+                if starts > 0 {
+                    '|'
+                } else {
+                    self.segments[segment].trend.symbol()
+                }
+            })
+            .collect()
+    }
+}
+
+fn describe(run: Option<Run>, depths: &[u32]) -> String {
+    match run {
+        Some(run) => format!(
+            "{} measurements, {} to {} (depths {} to {})",
+            run.count(),
+            run.start + 1,
+            run.end + 1,
+            depths[run.start],
+            depths[run.end]
+        ),
+        None => String::from("none"),
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let depths = &self.depths;
+        writeln!(f, "Sonar sweep of {} measurements", depths.len())?;
+        if depths.is_empty() {
+            return Ok(());
+        }
+
+        let columns = self.columns();
+        writeln!(f)?;
+        writeln!(f, "  {}", self.sparkline(&columns))?;
+        writeln!(f, "  {}", self.annotations(&columns))?;
+        writeln!(f)?;
+
+        writeln!(
+            f,
+            "Longest increasing run: {}",
+            describe(self.longest_increase, depths)
+        )?;
+        writeln!(
+            f,
+            "Longest decreasing run: {}",
+            describe(self.longest_decrease, depths)
+        )?;
+        writeln!(
+            f,
+            "Longest plateau:        {}",
+            describe(self.longest_plateau, depths)
+        )?;
+
+        writeln!(f, "Largest drops:")?;
+        if self.drops.is_empty() {
+            writeln!(f, "  none")?;
+        }
+        for drop in self.drops.iter() {
+            writeln!(
+                f,
+                "  {} at measurement {} ({} to {})",
+                drop.from - drop.to,
+                drop.index + 1,
+                drop.from,
+                drop.to
+            )?;
+        }
+
+        writeln!(f, "Trend segments (+ deepening, - shallowing, = level):")?;
+        for (j, segment) in self.segments.iter().enumerate() {
+            let Run { start, end } = segment.run;
+            writeln!(
+                f,
+                "  {}. {:?} over measurements {} to {}, depth {} to {}",
+                j + 1,
+                segment.trend,
+                start + 1,
+                end + 1,
+                depths[start],
+                depths[end]
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_drops_and_segments() {
+        let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let report = Report::new(&depths, 3, 80);
+
+        // The first of the longest runs, as 200 to 269 is as long
+        assert_eq!(report.longest_increase, Some(Run { start: 0, end: 3 }));
+        assert_eq!(report.longest_decrease, Some(Run { start: 3, end: 4 }));
+        assert_eq!(report.longest_plateau, None);
+        assert_eq!(report.drops[0].index, 4);
+        assert_eq!(report.drops.len(), 2);
+
+        // Every measurement is in exactly one segment
+        assert_eq!(report.segments[0].run.start, 0);
+        assert_eq!(report.segments.last().unwrap().run.end, depths.len() - 1);
+        for pair in report.segments.windows(2) {
+            assert_eq!(pair[0].run.end + 1, pair[1].run.start);
+        }
+    }
+
+    #[test]
+    fn chart_fits_width() {
+        let depths: Vec<u32> = (0..1000).map(|j| 100 + (j % 300)).collect();
+        let report = Report::new(&depths, 5, 40);
+        let columns = report.columns();
+
+        assert_eq!(report.sparkline(&columns).chars().count(), 40);
+        assert_eq!(report.annotations(&columns).chars().count(), 40);
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::VecDeque;
+
+/// The most measurements a window can hold, so that its sum always fits in
+/// a `u64`.
+pub const MAX_WINDOW: usize = u32::MAX as usize;
+
+/// The last `size` measurements and their sum, updated as each measurement
+/// arrives by adding it and taking away the one leaving the window.
+#[derive(Debug, Clone)]
+pub struct Window {
+    size: usize,
+    measurements: VecDeque<u32>,
+    sum: u64,
+}
+
+impl Window {
+    /// Panics if `size` is zero or more than `MAX_WINDOW`.
+    pub fn new(size: usize) -> Window {
+        assert!(size > 0, "a window holds at least one measurement");
+        assert!(
+            size <= MAX_WINDOW,
+            "a window holds at most {} measurements",
+            MAX_WINDOW
+        );
+
+        Window {
+            size,
+            measurements: VecDeque::new(),
+            sum: 0,
+        }
+    }
+
+    /// Add the next measurement, returning the sum of the window once
+    /// there are enough measurements to fill it.
+    pub fn push(&mut self, measurement: u32) -> Option<u64> {
+        self.measurements.push_back(measurement);
+        self.sum += u64::from(measurement);
+        if self.measurements.len() > self.size {
+            self.sum -= u64::from(self.measurements.pop_front().unwrap());
+        }
+
+        if self.measurements.len() < self.size {
+            return None;
+        }
+
+        Some(self.sum)
+    }
+}
+
+/// The sums of each `size` consecutive measurements, sliding along one
+/// measurement at a time.
+#[derive(Debug, Clone)]
+pub struct WindowSums<I> {
+    measurements: I,
+    window: Window,
+}
+
+impl<I: Iterator<Item = u32>> Iterator for WindowSums<I> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            let measurement = self.measurements.next()?;
+            if let Some(sum) = self.window.push(measurement) {
+                return Some(sum);
+            }
+        }
+    }
+}
+
+pub trait WindowSumsExt: Iterator<Item = u32> + Sized {
+    /// Panics if `size` is zero or more than `MAX_WINDOW`.
+    fn window_sums(self, size: usize) -> WindowSums<Self> {
+        WindowSums {
+            measurements: self,
+            window: Window::new(size),
+        }
+    }
+}
+
+impl<I: Iterator<Item = u32>> WindowSumsExt for I {}
+
+/// A count of the sums which are larger than the sum `step` windows
+/// before, kept up to date as each sum arrives.
+#[derive(Debug, Clone)]
+pub struct Increases {
+    step: usize,
+    earlier: VecDeque<u64>,
+    count: u64,
+}
+
+impl Increases {
+    /// Panics if `step` is zero.
+    pub fn new(step: usize) -> Increases {
+        assert!(
+            step > 0,
+            "sums are compared with at least one window before"
+        );
+
+        Increases {
+            step,
+            earlier: VecDeque::new(),
+            count: 0,
+        }
+    }
+
+    pub fn push(&mut self, sum: u64) {
+        self.earlier.push_back(sum);
+        if self.earlier.len() > self.step && self.earlier.pop_front().unwrap() < sum {
+            self.count += 1;
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+/// Count the sums which are larger than the sum `step` windows before.
+/// Panics if `step` is zero.
+pub fn count_increases(sums: impl Iterator<Item = u64>, step: usize) -> u64 {
+    let mut increases = Increases::new(step);
+
+    for sum in sums {
+        increases.push(sum);
+    }
+
+    increases.count()
+}
+
+/// Counts increases in the sums of a window of measurements as they
+/// arrive, keeping only the last few measurements and sums, for feeds
+/// which never end.
+#[derive(Debug, Clone)]
+pub struct LiveIncreases {
+    window: Window,
+    increases: Increases,
+}
+
+impl LiveIncreases {
+    /// Panics if `size` or `step` is zero, or `size` is more than
+    /// `MAX_WINDOW`.
+    pub fn new(size: usize, step: usize) -> LiveIncreases {
+        LiveIncreases {
+            window: Window::new(size),
+            increases: Increases::new(step),
+        }
+    }
+
+    /// Add the next measurement, returning the count so far.
+    pub fn push(&mut self, measurement: u32) -> u64 {
+        if let Some(sum) = self.window.push(measurement) {
+            self.increases.push(sum);
+        }
+
+        self.increases.count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums_and_steps() {
+        let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+        let sums: Vec<_> = depths.into_iter().window_sums(3).collect();
+        assert_eq!(sums[..3], [607, 618, 618]);
+        assert_eq!(sums.len(), 8);
+
+        let increases = |size, step| count_increases(depths.into_iter().window_sums(size), step);
+        assert_eq!(increases(1, 1), 7);
+        assert_eq!(increases(3, 1), 5);
+        assert_eq!(increases(1, 3), 5);
+        assert_eq!(increases(11, 1), 0);
+
+        let mut live = LiveIncreases::new(3, 1);
+        let counts: Vec<u64> = depths.iter().map(|&depth| live.push(depth)).collect();
+        assert_eq!(counts, [0, 0, 0, 1, 1, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn wide_sums() {
+        let depths = [1, u32::MAX, 0, 0];
+
+        let sums: Vec<_> = depths.into_iter().window_sums(2).collect();
+        assert_eq!(sums, [1 << 32, u64::from(u32::MAX), 0]);
+        assert_eq!(count_increases(depths.into_iter().window_sums(2), 1), 0);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::Command;
+use common::Problem;
+use std::collections::HashMap;
+
+// Expansion stops here, so that a few nested repeats can't fill memory
+const MAX_COMMANDS: usize = 1 << 22;
+
+/// Commands, each with where it was read from, as an index into the
+/// sources of the course.
+pub(crate) type Orders = Vec<(Command, usize)>;
+
+#[derive(Debug)]
+enum Opened {
+    Repeat(u32),
+    Macro(String),
+}
+
+#[derive(Debug)]
+struct Frame {
+    opened: Opened,
+    line: usize,
+    line_text: String,
+    body: Orders,
+}
+
+/// The blocks open while a course is read a line at a time, and the macros
+/// defined so far. Each block is expanded into plain commands as it closes.
+#[derive(Debug, Default)]
+pub(crate) struct Blocks {
+    frames: Vec<Frame>,
+    macros: HashMap<String, Orders>,
+}
+
+impl Blocks {
+    // Where commands go: the innermost open block, or else the course
+    fn target<'a>(&'a mut self, course: &'a mut Orders) -> &'a mut Orders {
+        match self.frames.last_mut() {
+            Some(frame) => &mut frame.body,
+            None => course,
+        }
+    }
+
+    fn extend(target: &mut Orders, orders: &[(Command, usize)], times: u32) -> Result<(), String> {
+        let grown = orders
+            .len()
+            .checked_mul(times as usize)
+            .and_then(|added| added.checked_add(target.len()));
+        if grown.is_none_or(|grown| grown > MAX_COMMANDS) {
+            return Err(format!(
+                "the course expands to more than {} commands",
+                MAX_COMMANDS
+            ));
+        }
+
+        for _ in 0..times {
+            target.extend_from_slice(orders);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn push(&mut self, course: &mut Orders, command: Command, source: usize) {
+        self.target(course).push((command, source));
+    }
+
+    pub(crate) fn open_repeat(&mut self, times: u32, line: usize, line_text: &str) {
+        self.open(Opened::Repeat(times), line, line_text);
+    }
+
+    pub(crate) fn open_macro(
+        &mut self,
+        name: &str,
+        line: usize,
+        line_text: &str,
+    ) -> Result<(), String> {
+        if !self.frames.is_empty() {
+            return Err(String::from("macros can only be defined outside blocks"));
+        }
+        if self.macros.contains_key(name) {
+            return Err(format!("macro {} is already defined", name));
+        }
+
+        self.open(Opened::Macro(String::from(name)), line, line_text);
+
+        Ok(())
+    }
+
+    fn open(&mut self, opened: Opened, line: usize, line_text: &str) {
+        self.frames.push(Frame {
+            opened,
+            line,
+            line_text: String::from(line_text),
+            body: Vec::new(),
+        });
+    }
+
+    pub(crate) fn close(&mut self, course: &mut Orders) -> Result<(), String> {
+        let frame = self
+            .frames
+            .pop()
+            .ok_or_else(|| String::from("there is no block to close"))?;
+
+        match frame.opened {
+            Opened::Repeat(times) => Blocks::extend(self.target(course), &frame.body, times),
+            Opened::Macro(name) => {
+                self.macros.insert(name, frame.body);
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) fn call(&mut self, course: &mut Orders, name: &str) -> Result<(), String> {
+        let body = self
+            .macros
+            .get(name)
+            .ok_or_else(|| format!("no macro named {} has been defined", name))?
+            .clone();
+
+        Blocks::extend(self.target(course), &body, 1)
+    }
+
+    /// A problem for each block left open at the end of the course.
+    pub(crate) fn unclosed(&self) -> Vec<Problem> {
+        self.frames
+            .iter()
+            .map(|frame| {
+                let column = frame.line_text.len() - frame.line_text.trim_start().len() + 1;
+                Problem::new(
+                    frame.line,
+                    column,
+                    &frame.line_text,
+                    frame.line_text.trim(),
+                    "this block is never closed",
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day02, Direct, Surface};
+    use common::Solution;
+
+    // The sample course, written with a macro and a repeat
+    const COURSE: &str = "\
+# Part of the sample
+macro dive {
+    down 8
+    forward 2  # along the aim
+}
+
+forward 5
+down 5
+repeat 2 {
+    forward 4
+}
+up 3
+dive
+back 0
+";
+
+    #[test]
+    fn blocks_expand() {
+        let course = Day02::parse(COURSE).unwrap();
+        assert_eq!(course.part_1(), Ok(150));
+        assert_eq!(course.part_2(), Ok(900));
+
+        // Commands keep the lines they were written on
+        let trajectory = course.trajectory(&Direct, Surface::Error).unwrap();
+        let lines: Vec<usize> = trajectory.steps.iter().map(|step| step.line).collect();
+        assert_eq!(lines, [7, 8, 10, 10, 12, 3, 4, 14]);
+    }
+
+    #[test]
+    fn unclosed_blocks() {
+        let error = Day02::parse("repeat 2 {\n  forward 1\n").unwrap_err();
+
+        assert_eq!(error.problems.len(), 1);
+        assert_eq!(error.problems[0].line, 1);
+        assert_eq!(error.problems[0].message, "this block is never closed");
+    }
+}
@@ -0,0 +1,31 @@
+use crate::Day02;
+use common::{Generate, Rng};
+use std::fmt::Write;
+
+// The depth in part 1 is the aim in part 2, and neither may go negative
+impl Generate for Day02 {
+    // Commands
+    const SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut aim = 0;
+        let mut result = String::new();
+
+        for _ in 0..size {
+            let units = rng.range(1, 9);
+            match rng.below(3) {
+                0 => writeln!(result, "forward {}", units).unwrap(),
+                1 if aim >= units => {
+                    aim -= units;
+                    writeln!(result, "up {}", units).unwrap();
+                }
+                _ => {
+                    aim += units;
+                    writeln!(result, "down {}", units).unwrap();
+                }
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,178 @@
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+mod block;
+mod generate;
+mod model;
+mod plan;
+mod surface;
+mod trajectory;
+
+use block::{Blocks, Orders};
+use common::{capture, parse_streamed, NoAnswer, ParseError, Problem, Solution, Streaming};
+pub use model::{model, Aimed, Direct, Momentum, NavigationModel, Submarine, MODELS};
+use pest::Parser;
+pub use plan::{Goal, Target, GOALS};
+pub use surface::{Broach, Broached, Source, Surface, Voyage, SURFACES};
+pub use trajectory::{SideView, Step, Trajectory};
+
+#[derive(Parser)]
+#[grammar = "../../common/src/numbers.pest"]
+#[grammar = "command.pest"]
+struct CommandParser;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(u32),
+    Down(u32),
+    Up(u32),
+    Back(u32),
+}
+
+#[derive(Debug, Default)]
+pub struct Day02 {
+    commands: Orders,
+    // Where each command statement was read from
+    sources: Vec<Source>,
+    // Counting lines from one
+    read: usize,
+    blocks: Blocks,
+}
+
+impl Solution for Day02 {
+    type Part1 = Result<i128, NoAnswer>;
+    type Part2 = Result<i128, NoAnswer>;
+
+    fn parse(input: &str) -> Result<Day02, ParseError> {
+        parse_streamed(input)
+    }
+
+    fn part_1(&self) -> Result<i128, NoAnswer> {
+        self.answer(&Direct)
+    }
+
+    fn part_2(&self) -> Result<i128, NoAnswer> {
+        self.answer(&Aimed)
+    }
+}
+
+impl Day02 {
+    /// Follow the whole course from the surface under the given model,
+    /// treating the surface as `surface` says.
+    pub fn navigate(
+        &self,
+        model: &(impl NavigationModel + ?Sized),
+        surface: Surface,
+    ) -> Result<Voyage, Broach> {
+        self.follow(model, surface, |_| {})
+    }
+
+    /// Follow the course as `navigate` does, noting where the submarine is
+    /// after every command.
+    pub fn trajectory(
+        &self,
+        model: &(impl NavigationModel + ?Sized),
+        surface: Surface,
+    ) -> Result<Trajectory, Broach> {
+        let mut steps = Vec::new();
+        let voyage = self.follow(model, surface, |step| steps.push(step))?;
+
+        Ok(Trajectory {
+            steps,
+            broaches: voyage.broaches,
+        })
+    }
+
+    // Only going out of range can stop a course followed regardless
+    fn answer(&self, model: &dyn NavigationModel) -> Result<i128, NoAnswer> {
+        let voyage = self
+            .navigate(model, Surface::Airborne)
+            .map_err(|broach| NoAnswer::new(broach.to_string()))?;
+
+        voyage
+            .submarine
+            .product()
+            .ok_or_else(|| NoAnswer::new("the final position and depth are too large to multiply"))
+    }
+
+    fn follow(
+        &self,
+        model: &(impl NavigationModel + ?Sized),
+        surface: Surface,
+        mut visit: impl FnMut(Step),
+    ) -> Result<Voyage, Broach> {
+        let mut voyage = Voyage::default();
+
+        for (index, &(command, source)) in self.commands.iter().enumerate() {
+            let source = &self.sources[source];
+            let before = voyage.submarine;
+            let after = model.apply(before, &command).ok_or_else(|| Broach {
+                index,
+                source: source.clone(),
+                kind: Broached::Overflow,
+            })?;
+            voyage.submarine = surface.check(before, after, index, source, &mut voyage.broaches)?;
+            visit(Step {
+                index,
+                line: source.line,
+                submarine: voyage.submarine,
+            });
+        }
+
+        Ok(voyage)
+    }
+}
+
+impl Streaming for Day02 {
+    fn parse_line(&mut self, line: &str) -> Result<(), ParseError> {
+        self.read += 1;
+        let pairs = CommandParser::parse(Rule::main, line)?;
+
+        for pair in pairs {
+            let (rule, span, read) = (pair.as_rule(), pair.as_span(), self.read);
+            let (commands, blocks) = (&mut self.commands, &mut self.blocks);
+            let at_span = |e| Problem::at_span(span, e);
+
+            // Each command notes where it was read from, which repeats and
+            // macros then copy along with it
+            let source = self.sources.len();
+            if matches!(rule, Rule::forward | Rule::down | Rule::up | Rule::back) {
+                self.sources.push(Source {
+                    line: read,
+                    column: span.start_pos().line_col().1,
+                    line_text: String::from(line.trim_end_matches('\r')),
+                    span: String::from(span.as_str()),
+                });
+            }
+
+            match rule {
+                Rule::forward => blocks.push(commands, Command::Forward(capture(pair)?), source),
+                Rule::down => blocks.push(commands, Command::Down(capture(pair)?), source),
+                Rule::up => blocks.push(commands, Command::Up(capture(pair)?), source),
+                Rule::back => blocks.push(commands, Command::Back(capture(pair)?), source),
+                Rule::repeat => blocks.open_repeat(capture(pair)?, read, line),
+                Rule::define => blocks
+                    .open_macro(capture(pair)?, read, line)
+                    .map_err(at_span)?,
+                Rule::close => blocks.close(commands).map_err(at_span)?,
+                Rule::call => blocks.call(commands, capture(pair)?).map_err(at_span)?,
+                Rule::EOI => {}
+                _ => {
+                    panic!("Unknown rule {:?} with {:?}", rule, pair.as_str());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(self, _lines: usize) -> Result<Day02, ParseError> {
+        let problems = self.blocks.unclosed();
+        if !problems.is_empty() {
+            return Err(ParseError::new(problems));
+        }
+
+        Ok(self)
+    }
+}
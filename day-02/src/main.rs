@@ -1,118 +1,237 @@
-extern crate pest;
-#[macro_use]
-extern crate pest_derive;
-
-use pest::Parser;
-use std::io::{stdin, Read};
-
-#[derive(Parser)]
-#[grammar = "command.pest"]
-struct CommandParser;
-
-#[derive(Debug)]
-enum Command {
-    Forward(u32),
-    Down(u32),
-    Up(u32),
+use common::{parse_stream, ParseError};
+use day_02::{
+    model, Aimed, Broach, Day02, Direct, Goal, NavigationModel, Surface, Target, Voyage, GOALS,
+    MODELS, SURFACES,
+};
+use std::env;
+use std::io::{self, stdin, BufRead, Write};
+use std::process;
+
+const USAGE: &str = "\
+Usage: day-02 [--model <MODEL>] [--surface <SURFACE>] < INPUT
+       day-02 (--csv | --plot) [--model <MODEL>] [--surface <SURFACE>] < INPUT
+       day-02 --plan <POSITION>,<DEPTH> [--model <MODEL>] [--goal <GOAL>]
+
+Options:
+  --model <MODEL>      also follow the course under MODEL, one of: direct
+                       (the reading in part 1), aimed (the reading in part
+                       2) or momentum (forward speeds the submarine up, and
+                       drag slows it down again)
+  --surface <SURFACE>  what to do when the submarine broaches the surface
+                       or its aim goes negative, one of: clamp (stop at the
+                       surface or a level aim), error (give up on the
+                       course) or airborne (carry on regardless, the
+                       default); each broach is reported
+  --csv                instead of the answers, print the position, depth
+                       and aim after each command under MODEL (default:
+                       aimed) as CSV
+  --plot               instead of the answers, plot depth against position
+                       under MODEL (default: aimed), seen from the side
+  --plan <POSITION>,<DEPTH>
+                       instead of reading a course, print the shortest
+                       course from the surface to POSITION and DEPTH under
+                       MODEL (default: aimed), which must be direct or
+                       aimed
+  --goal <GOAL>        what the planned course is shortest in, one of:
+                       commands (the default) or units";
+
+// The size of the plot
+const PLOT_WIDTH: usize = 72;
+const PLOT_HEIGHT: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Export {
+    Csv,
+    Plot,
 }
 
-#[derive(Debug)]
-struct State {
-    commands: Vec<Command>,
-    position: u32,
-    depth: u32,
-    aim: u32,
+#[derive(Debug, Default)]
+struct Args {
+    model: Option<String>,
+    surface: Surface,
+    export: Option<Export>,
+    plan: Option<Target>,
+    goal: Option<Goal>,
 }
 
-impl State {
-    fn new() -> State {
-        let commands = Vec::new();
+fn choice<T>(arg: &str, name: &str, choices: &[(&str, T)]) -> String {
+    let names: Vec<&str> = choices.iter().map(|(name, _)| *name).collect();
 
-        State {
-            commands,
-            position: 0,
-            depth: 0,
-            aim: 0,
-        }
-    }
-
-    fn parse_line(&mut self, line: &str) {
-        let pairs = CommandParser::parse(Rule::command, line).unwrap_or_else(|e| panic!("{}", e));
+    format!(
+        "invalid value {:?} for {}, expected one of {}",
+        name,
+        arg,
+        names.join(", ")
+    )
+}
 
-        for pair in pairs {
-            let rule = pair.as_rule();
-            let units = pair.into_inner().next().unwrap().as_str().parse().unwrap();
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args::default();
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        let export = match arg.as_str() {
+            "--csv" => Some(Export::Csv),
+            "--plot" => Some(Export::Plot),
+            _ => None,
+        };
+        if export.is_some() {
+            if parsed.export.is_some() {
+                return Err(String::from("--csv cannot be used with --plot"));
+            }
+            parsed.export = export;
+            continue;
+        }
 
-            match rule {
-                Rule::forward => {
-                    self.commands.push(Command::Forward(units));
-                }
-                Rule::down => {
-                    self.commands.push(Command::Down(units));
-                }
-                Rule::up => {
-                    self.commands.push(Command::Up(units));
-                }
-                _ => {
-                    dbg!(&rule);
+        let name = match arg.as_str() {
+            "--model" | "--surface" | "--plan" | "--goal" => args
+                .next()
+                .ok_or_else(|| format!("{} requires a value", arg))?,
+            _ => return Err(format!("unknown argument {}", arg)),
+        };
+
+        match arg.as_str() {
+            "--model" => {
+                if model(&name).is_none() {
+                    return Err(choice(&arg, &name, &MODELS));
                 }
+                parsed.model = Some(name);
+            }
+            "--surface" => {
+                parsed.surface =
+                    Surface::named(&name).ok_or_else(|| choice(&arg, &name, &SURFACES))?;
+            }
+            "--goal" => {
+                parsed.goal = Some(Goal::named(&name).ok_or_else(|| choice(&arg, &name, &GOALS))?);
+            }
+            _ => {
+                let target = name.split_once(',').and_then(|(position, depth)| {
+                    Some((position.parse().ok()?, depth.parse().ok()?))
+                });
+                let (position, depth) =
+                    target.ok_or_else(|| format!("invalid value {:?} for {}", name, arg))?;
+                parsed.plan = Some(Target { position, depth });
             }
         }
     }
 
-    fn process_commands_part1(&mut self) {
-        for command in self.commands.iter() {
-            match command {
-                Command::Forward(units) => self.position += units,
-                Command::Down(units) => self.depth += units,
-                Command::Up(units) => self.depth -= units,
-            }
-        }
+    if parsed.plan.is_some() && parsed.export.is_some() {
+        return Err(String::from("--plan cannot be used with --csv or --plot"));
+    }
+    if parsed.goal.is_some() && parsed.plan.is_none() {
+        return Err(String::from("--goal requires --plan"));
     }
 
-    fn process_commands_part2(&mut self) {
-        // Reinitialize position and depth
-        self.position = 0;
-        self.depth = 0;
+    Ok(parsed)
+}
 
-        for command in self.commands.iter() {
-            match command {
-                Command::Forward(units) => {
-                    self.position += units;
-                    self.depth += self.aim * units;
-                }
-                Command::Down(units) => self.aim += units,
-                Command::Up(units) => self.aim -= units,
-            }
-        }
+// Point at the command which broke off the course, and give up
+fn fail(broach: Broach) -> ! {
+    eprint!("{}", ParseError::from(broach.problem()));
+    process::exit(1);
+}
+
+// Report each broach on the way, or give up at the first under `error`
+fn follow(solution: &Day02, model: &dyn NavigationModel, surface: Surface) -> Voyage {
+    let voyage = solution
+        .navigate(model, surface)
+        .unwrap_or_else(|e| fail(e));
+
+    warn(&voyage.broaches);
+
+    voyage
+}
+
+fn product(voyage: &Voyage) -> i128 {
+    voyage.submarine.product().unwrap_or_else(|| {
+        eprintln!("error: the final position and depth are too large to multiply");
+        process::exit(1);
+    })
+}
+
+fn warn(broaches: &[Broach]) {
+    for broach in broaches {
+        eprintln!("warning: {}", broach);
     }
 }
 
 fn main() {
-    let mut input = String::new();
-    stdin().read_to_string(&mut input).unwrap();
-
-    let mut state = State::new();
+    let args = parse_args(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("error: {}\n\n{}", e, USAGE);
+        process::exit(2);
+    });
+
+    if let Some(target) = args.plan {
+        let name = args.model.as_deref().unwrap_or("aimed");
+        let plan = model(name)
+            .unwrap()
+            .plan(target, args.goal.unwrap_or_default())
+            .unwrap_or_else(|| {
+                eprintln!(
+                    "error: cannot plan a course to position {} and depth {} under the {} model",
+                    target.position, target.depth, name
+                );
+                process::exit(1);
+            });
+        for command in plan {
+            println!("{}", command);
+        }
+        return;
+    }
 
-    for line in input.lines() {
-        state.parse_line(&line);
+    let solution: Day02 = parse_stream(stdin().lock().lines()).unwrap_or_else(|e| {
+        eprint!("{}", e);
+        process::exit(1);
+    });
+
+    if let Some(export) = args.export {
+        let name = args.model.as_deref().unwrap_or("aimed");
+        let trajectory = solution
+            .trajectory(model(name).unwrap(), args.surface)
+            .unwrap_or_else(|e| fail(e));
+        warn(&trajectory.broaches);
+        let mut out = io::stdout().lock();
+        let written = match export {
+            Export::Csv => trajectory.write_csv(&mut out),
+            Export::Plot => write!(out, "{}", trajectory.side_view(PLOT_WIDTH, PLOT_HEIGHT)),
+        };
+        match written.and_then(|_| out.flush()) {
+            Ok(()) => {}
+            // Whatever reads the output has stopped
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {}
+            Err(e) => {
+                eprintln!("error: {}", e);
+                process::exit(1);
+            }
+        }
+        return;
     }
 
     // Part 1
 
-    state.process_commands_part1();
-
     println!(
         "Part 1: the product of the final position and final depth is {}",
-        state.position * state.depth
+        product(&follow(&solution, &Direct, args.surface))
     );
 
     // Part 2
 
-    state.process_commands_part2();
-
     println!(
         "Part 2: the product of the final position and final depth is {}",
-        state.position * state.depth
+        product(&follow(&solution, &Aimed, args.surface))
     );
+
+    // Any other model
+
+    if let Some(name) = args.model {
+        let voyage = follow(&solution, model(&name).unwrap(), args.surface);
+        let submarine = voyage.submarine;
+        println!(
+            "Model {}: the final position is {} and the final depth is {}, with a product of {}",
+            name,
+            submarine.position,
+            submarine.depth,
+            product(&voyage)
+        );
+    }
 }
@@ -0,0 +1,167 @@
+use crate::plan::{self, Goal, Target};
+use crate::Command;
+
+/// Where the submarine has got to, and whatever each model needs to keep
+/// track of along the way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Submarine {
+    pub position: i128,
+    pub depth: i128,
+    pub aim: i128,
+    pub speed: i128,
+}
+
+impl Submarine {
+    pub fn product(&self) -> Option<i128> {
+        self.position.checked_mul(self.depth)
+    }
+}
+
+/// One way of reading the commands, which takes the submarine from one
+/// state to the next, or gives `None` if the next state is too far away
+/// to hold.
+pub trait NavigationModel: Sync {
+    fn apply(&self, submarine: Submarine, command: &Command) -> Option<Submarine>;
+
+    /// The shortest course by `goal` from the surface to `target`, if this
+    /// model knows how to plan one.
+    fn plan(&self, _target: Target, _goal: Goal) -> Option<Vec<Command>> {
+        None
+    }
+}
+
+/// Part 1: down and up change the depth directly.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Direct;
+
+impl NavigationModel for Direct {
+    fn apply(&self, mut submarine: Submarine, command: &Command) -> Option<Submarine> {
+        match *command {
+            Command::Forward(units) => {
+                submarine.position = submarine.position.checked_add(i128::from(units))?
+            }
+            Command::Down(units) => {
+                submarine.depth = submarine.depth.checked_add(i128::from(units))?
+            }
+            Command::Up(units) => {
+                submarine.depth = submarine.depth.checked_sub(i128::from(units))?
+            }
+            Command::Back(units) => {
+                submarine.position = submarine.position.checked_sub(i128::from(units))?
+            }
+        }
+
+        Some(submarine)
+    }
+
+    fn plan(&self, target: Target, _goal: Goal) -> Option<Vec<Command>> {
+        plan::direct(target)
+    }
+}
+
+/// Part 2: down and up change the aim, and forward follows it, as back
+/// retraces it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Aimed;
+
+impl NavigationModel for Aimed {
+    fn apply(&self, mut submarine: Submarine, command: &Command) -> Option<Submarine> {
+        match *command {
+            Command::Forward(units) => {
+                submarine.position = submarine.position.checked_add(i128::from(units))?;
+                submarine.depth = submarine
+                    .depth
+                    .checked_add(submarine.aim.checked_mul(i128::from(units))?)?;
+            }
+            Command::Down(units) => submarine.aim = submarine.aim.checked_add(i128::from(units))?,
+            Command::Up(units) => submarine.aim = submarine.aim.checked_sub(i128::from(units))?,
+            Command::Back(units) => {
+                submarine.position = submarine.position.checked_sub(i128::from(units))?;
+                submarine.depth = submarine
+                    .depth
+                    .checked_sub(submarine.aim.checked_mul(i128::from(units))?)?;
+            }
+        }
+
+        Some(submarine)
+    }
+
+    fn plan(&self, target: Target, goal: Goal) -> Option<Vec<Command>> {
+        plan::aimed(target, goal)
+    }
+}
+
+/// Like `Aimed`, but forward speeds the submarine up rather than moving it,
+/// and back slows it down.
+/// Every command takes the same time, in which the submarine glides along
+/// its aim at its speed, and then drag halves the speed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Momentum;
+
+impl NavigationModel for Momentum {
+    fn apply(&self, mut submarine: Submarine, command: &Command) -> Option<Submarine> {
+        match *command {
+            Command::Forward(units) => {
+                submarine.speed = submarine.speed.checked_add(i128::from(units))?
+            }
+            Command::Down(units) => submarine.aim = submarine.aim.checked_add(i128::from(units))?,
+            Command::Up(units) => submarine.aim = submarine.aim.checked_sub(i128::from(units))?,
+            Command::Back(units) => {
+                submarine.speed = submarine.speed.checked_sub(i128::from(units))?
+            }
+        }
+
+        submarine.position = submarine.position.checked_add(submarine.speed)?;
+        submarine.depth = submarine
+            .depth
+            .checked_add(submarine.aim.checked_mul(submarine.speed)?)?;
+        submarine.speed /= 2;
+
+        Some(submarine)
+    }
+}
+
+/// The models which can be chosen by name.
+pub const MODELS: [(&str, &dyn NavigationModel); 3] = [
+    ("direct", &Direct),
+    ("aimed", &Aimed),
+    ("momentum", &Momentum),
+];
+
+pub fn model(name: &str) -> Option<&'static dyn NavigationModel> {
+    MODELS
+        .iter()
+        .find(|(model, _)| *model == name)
+        .map(|(_, model)| *model)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day02, Surface};
+    use common::Solution;
+
+    const SAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+";
+
+    #[test]
+    fn models_share_one_course() {
+        let course = Day02::parse(SAMPLE).unwrap();
+
+        let navigate =
+            |model: &dyn NavigationModel| course.navigate(model, Surface::Error).unwrap().submarine;
+
+        assert_eq!(navigate(&Direct).product(), Some(150));
+        assert_eq!(navigate(&Aimed).product(), Some(900));
+
+        // Speeds 5, 2, 9, 4, 2, 3 and aims 0, 5, 5, 2, 10, 10
+        let submarine = navigate(model("momentum").unwrap());
+        assert_eq!((submarine.position, submarine.depth), (25, 113));
+    }
+}
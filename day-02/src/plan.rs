@@ -0,0 +1,259 @@
+use crate::Command;
+use std::fmt;
+
+/// What a planned course should use as little of as it can.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Goal {
+    #[default]
+    Commands,
+    Units,
+}
+
+/// The goals which can be chosen by name.
+pub const GOALS: [(&str, Goal); 2] = [("commands", Goal::Commands), ("units", Goal::Units)];
+
+impl Goal {
+    pub fn named(name: &str) -> Option<Goal> {
+        GOALS
+            .iter()
+            .find(|(goal, _)| *goal == name)
+            .map(|(_, goal)| *goal)
+    }
+}
+
+/// Where a planned course should end, counting depth down from the
+/// surface.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Target {
+    pub position: i64,
+    pub depth: i64,
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Forward(units) => write!(f, "forward {}", units),
+            Command::Down(units) => write!(f, "down {}", units),
+            Command::Up(units) => write!(f, "up {}", units),
+            Command::Back(units) => write!(f, "back {}", units),
+        }
+    }
+}
+
+// A course as signed moves, where a negative forward is a back and a
+// negative down is an up, dropping the moves which do nothing
+fn course(moves: &[(Command, i64)]) -> Vec<Command> {
+    moves
+        .iter()
+        .filter(|(_, units)| *units != 0)
+        .map(|&(command, units)| {
+            let size = units.unsigned_abs() as u32;
+            match (command, units > 0) {
+                (Command::Forward(_), true) => Command::Forward(size),
+                (Command::Forward(_), false) => Command::Back(size),
+                (_, true) => Command::Down(size),
+                (_, false) => Command::Up(size),
+            }
+        })
+        .collect()
+}
+
+// Every planned command fits in a `u32` when the target does
+fn reachable(target: Target) -> Option<(i64, i64)> {
+    let limit = i64::from(u32::MAX);
+    (target.position.abs() <= limit && target.depth.abs() <= limit)
+        .then_some((target.position, target.depth))
+}
+
+/// Plan a course for part 1's reading, where the cheapest course is the
+/// same whatever the goal: one move across and one down or up.
+pub(crate) fn direct(target: Target) -> Option<Vec<Command>> {
+    let (x, d) = reachable(target)?;
+
+    Some(course(&[(Command::Forward(0), x), (Command::Down(0), d)]))
+}
+
+// The fewest commands reaching (x, d), both at least zero, when down and
+// up only turn the aim: depth has to come from moving at an aim, and a
+// single aim can only give a multiple of the distance moved
+fn fewest_aimed(x: i64, d: i64) -> Vec<(Command, i64)> {
+    let (forward, down) = (Command::Forward(0), Command::Down(0));
+
+    if d == 0 {
+        vec![(forward, x)]
+    } else if x == 0 {
+        vec![(forward, -1), (down, d), (forward, 1)]
+    } else if d % x == 0 {
+        vec![(down, d / x), (forward, x)]
+    } else {
+        vec![(forward, x - 1), (down, d), (forward, 1)]
+    }
+}
+
+// The lowest total units reaching (x, d), both at least zero. Moving `f`
+// units at an aim of at most `a` reaches a depth of at most `a * f`, and
+// the rest of the way across is moved level first, so the cost is
+// `a + f + |x - f|`; try every `f` which could be cheapest
+fn cheapest_aimed(x: i64, d: i64) -> Vec<(Command, i64)> {
+    let (forward, down) = (Command::Forward(0), Command::Down(0));
+    if d == 0 {
+        return vec![(forward, x)];
+    }
+
+    // Past `x`, each extra unit costs two, and it saves at most one once
+    // `f` is past the square root of `d`
+    let start = x.max(1);
+    let end = (start + (d as f64).sqrt() as i64 + 2).min(i64::from(u32::MAX));
+    let aim = |f: i64| (d + f - 1) / f;
+    let f = (start..=end)
+        .min_by_key(|&f| aim(f) + f + (x - f).abs())
+        .unwrap();
+
+    // Spread the depth over two aims a unit apart, so that it comes out
+    // exactly: `a * f - e` with `e` units at the lower aim
+    let a = aim(f);
+    let e = a * f - d;
+    if e == 0 {
+        return vec![(forward, x - f), (down, a), (forward, f)];
+    }
+    vec![
+        (forward, x - f),
+        (down, a - 1),
+        (forward, e),
+        (down, 1),
+        (forward, f - e),
+    ]
+}
+
+/// Plan a course for part 2's reading. The plan is made for a target to
+/// the right of and below the start, and mirrored into place: backing up
+/// at the opposite aim gives the same depth as going forward.
+pub(crate) fn aimed(target: Target, goal: Goal) -> Option<Vec<Command>> {
+    let (x, d) = reachable(target)?;
+
+    let mut moves = match goal {
+        Goal::Commands => fewest_aimed(x.abs(), d.abs()),
+        Goal::Units => cheapest_aimed(x.abs(), d.abs()),
+    };
+    for (command, units) in moves.iter_mut() {
+        let flip = match command {
+            Command::Forward(_) => x < 0,
+            _ => (x < 0) != (d < 0),
+        };
+        if flip {
+            *units = -*units;
+        }
+    }
+
+    Some(course(&moves))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Aimed, Day02, Direct, NavigationModel, Surface};
+    use common::Solution;
+    use std::collections::{HashMap, VecDeque};
+
+    fn follow(model: &dyn NavigationModel, commands: &[Command]) -> Target {
+        let text: String = commands.iter().map(|c| format!("{}\n", c)).collect();
+        let course = Day02::parse(&text).unwrap();
+        let submarine = course.navigate(model, Surface::Airborne).unwrap().submarine;
+
+        Target {
+            position: submarine.position as i64,
+            depth: submarine.depth as i64,
+        }
+    }
+
+    // The lowest cost of reaching every nearby (position, depth) under
+    // part 2's reading, searched for with commands of every size
+    fn search(cost: fn(u32) -> usize) -> HashMap<(i64, i64), usize> {
+        const BOUND: i64 = 16;
+        let mut best = HashMap::new();
+        let mut queue = VecDeque::from([((0, 0, 0), 0)]);
+
+        // Costs only grow, so relax until nothing changes
+        while let Some(((x, d, a), spent)) = queue.pop_front() {
+            if best.get(&(x, d, a)).is_some_and(|&b| b <= spent) {
+                continue;
+            }
+            best.insert((x, d, a), spent);
+            for units in 1..=BOUND as u32 {
+                let u = i64::from(units);
+                for next in [
+                    (x + u, d + a * u, a),
+                    (x - u, d - a * u, a),
+                    (x, d, a + u),
+                    (x, d, a - u),
+                ] {
+                    if [next.0, next.1, next.2].iter().all(|v| v.abs() <= BOUND) {
+                        queue.push_back((next, spent + cost(units)));
+                    }
+                }
+            }
+        }
+
+        let mut costs = HashMap::new();
+        for ((x, d, _), spent) in best {
+            let entry = costs.entry((x, d)).or_insert(spent);
+            *entry = (*entry).min(spent);
+        }
+        costs
+    }
+
+    #[test]
+    fn plans_reach_the_target() {
+        for position in -20..=20 {
+            for depth in -20..=20 {
+                let target = Target { position, depth };
+                assert_eq!(follow(&Direct, &direct(target).unwrap()), target);
+                for goal in [Goal::Commands, Goal::Units] {
+                    let plan = aimed(target, goal).unwrap();
+                    assert_eq!(follow(&Aimed, &plan), target, "{:?}", goal);
+                }
+            }
+        }
+
+        assert!(aimed(
+            Target {
+                position: 1 << 40,
+                depth: 0
+            },
+            Goal::Units
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn plans_are_shortest() {
+        let units = |command: &Command| match *command {
+            Command::Forward(u) | Command::Down(u) | Command::Up(u) | Command::Back(u) => {
+                u as usize
+            }
+        };
+        let fewest = search(|_| 1);
+        let cheapest = search(|units| units as usize);
+
+        for position in -7..=7 {
+            for depth in -7..=7 {
+                let target = Target { position, depth };
+                let key = (position, depth);
+
+                assert_eq!(
+                    aimed(target, Goal::Commands).unwrap().len(),
+                    fewest[&key],
+                    "{:?}",
+                    key
+                );
+                let plan = aimed(target, Goal::Units).unwrap();
+                assert_eq!(
+                    plan.iter().map(units).sum::<usize>(),
+                    cheapest[&key],
+                    "{:?}",
+                    key
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::Submarine;
+use common::Problem;
+use std::error::Error;
+use std::fmt;
+
+/// What to do when a command would take the submarine above the surface,
+/// or point its aim upwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Surface {
+    /// Stop at the surface, and at a level aim
+    Clamp,
+    /// Give up on the course
+    Error,
+    /// Carry on with a negative depth or aim
+    #[default]
+    Airborne,
+}
+
+/// The policies which can be chosen by name.
+pub const SURFACES: [(&str, Surface); 3] = [
+    ("clamp", Surface::Clamp),
+    ("error", Surface::Error),
+    ("airborne", Surface::Airborne),
+];
+
+impl Surface {
+    pub fn named(name: &str) -> Option<Surface> {
+        SURFACES
+            .iter()
+            .find(|(surface, _)| *surface == name)
+            .map(|(_, surface)| *surface)
+    }
+
+    /// Look over the move from `before` to `after` made by the command at
+    /// `index`, read from `source`, noting where the submarine broached
+    /// the surface or its aim went negative, and give where it ends up.
+    pub(crate) fn check(
+        self,
+        before: Submarine,
+        mut after: Submarine,
+        index: usize,
+        source: &Source,
+        broaches: &mut Vec<Broach>,
+    ) -> Result<Submarine, Broach> {
+        for (kind, was, is) in [
+            (Broached::Surface, before.depth, &mut after.depth),
+            (Broached::Aim, before.aim, &mut after.aim),
+        ] {
+            if was < 0 || *is >= 0 {
+                continue;
+            }
+
+            let broach = Broach {
+                index,
+                source: source.clone(),
+                kind,
+            };
+            match self {
+                Surface::Clamp => *is = 0,
+                Surface::Error => return Err(broach),
+                Surface::Airborne => {}
+            }
+            broaches.push(broach);
+        }
+
+        Ok(after)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Broached {
+    Surface,
+    Aim,
+    /// Gone further than can be tracked, which ends the course whatever
+    /// the policy
+    Overflow,
+}
+
+/// Where a command was read from, for pointing at it in diagnostics.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Source {
+    /// The line, counting from one
+    pub line: usize,
+    /// Where the command starts on the line, counting from one
+    pub column: usize,
+    pub line_text: String,
+    /// The text of the command
+    pub span: String,
+}
+
+/// Where the submarine broke through the surface, or started aiming up, or
+/// went out of range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Broach {
+    /// Which command, counting from zero
+    pub index: usize,
+    pub source: Source,
+    pub kind: Broached,
+}
+
+impl Broach {
+    /// The broach as a problem with the command which caused it, for
+    /// showing with a caret under the command.
+    pub fn problem(&self) -> Problem {
+        let source = &self.source;
+
+        Problem::new(
+            source.line,
+            source.column,
+            &source.line_text,
+            &source.span,
+            self.to_string(),
+        )
+    }
+}
+
+impl fmt::Display for Broach {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "command {} on line {} {}",
+            self.index + 1,
+            self.source.line,
+            match self.kind {
+                Broached::Surface => "takes the submarine above the surface",
+                Broached::Aim => "points the submarine's aim upwards",
+                Broached::Overflow => "takes the submarine further than can be tracked",
+            }
+        )
+    }
+}
+
+impl Error for Broach {}
+
+/// Where a course took the submarine, and where it broached on the way.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Voyage {
+    pub submarine: Submarine,
+    pub broaches: Vec<Broach>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Aimed, Day02, Direct};
+    use common::Solution;
+
+    const COURSE: &str = "\
+forward 5
+down 5
+forward 8
+up 6
+forward 2
+up 9
+forward 1
+";
+
+    #[test]
+    fn broaches_follow_the_policy() {
+        let course = Day02::parse(COURSE).unwrap();
+        let broach = |index: usize, kind| {
+            let line_text = COURSE.lines().nth(index).unwrap();
+            Broach {
+                index,
+                source: Source {
+                    line: index + 1,
+                    column: 1,
+                    line_text: String::from(line_text),
+                    span: String::from(line_text),
+                },
+                kind,
+            }
+        };
+
+        let airborne = course.navigate(&Direct, Surface::Airborne).unwrap();
+        assert_eq!(airborne.submarine.depth, -10);
+        assert_eq!(airborne.broaches, [broach(3, Broached::Surface)]);
+
+        // Clamped, the submarine is at the surface when the second up comes
+        let clamped = course.navigate(&Direct, Surface::Clamp).unwrap();
+        assert_eq!(clamped.submarine.depth, 0);
+        assert_eq!(
+            clamped.broaches,
+            [broach(3, Broached::Surface), broach(5, Broached::Surface)]
+        );
+
+        assert_eq!(
+            course.navigate(&Aimed, Surface::Error),
+            Err(broach(3, Broached::Aim))
+        );
+    }
+
+    #[test]
+    fn overflow_is_reported() {
+        let far = "down 4294967295\nforward 4294967295\n";
+        let course = Day02::parse(far).unwrap();
+        assert_eq!(course.part_2(), Ok(79228162458924105385300197375));
+
+        // About 2^53 across and 2^106 down
+        let further = "\
+repeat 2097152 {
+    down 4294967295
+}
+repeat 2097152 {
+    forward 4294967295
+}
+";
+        let course = Day02::parse(further).unwrap();
+        assert!(course.part_1().is_ok());
+        assert!(course
+            .part_2()
+            .unwrap_err()
+            .0
+            .contains("too large to multiply"));
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{Broach, Submarine};
+use std::fmt;
+use std::io::{self, Write};
+
+/// Where the submarine was after one command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    /// Which command, counting from zero
+    pub index: usize,
+    /// The line the command was read from, counting from one
+    pub line: usize,
+    pub submarine: Submarine,
+}
+
+/// Every place a course took the submarine, in order, starting from the
+/// surface, and where it broached on the way.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trajectory {
+    pub steps: Vec<Step>,
+    pub broaches: Vec<Broach>,
+}
+
+impl Trajectory {
+    fn submarines(&self) -> impl Iterator<Item = Submarine> + '_ {
+        std::iter::once(Submarine::default()).chain(self.steps.iter().map(|step| step.submarine))
+    }
+
+    /// Write a header and then a row for the start and after each command,
+    /// leaving the line of the start empty.
+    pub fn write_csv(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "command,line,position,depth,aim")?;
+        writeln!(out, "0,,0,0,0")?;
+        for step in self.steps.iter() {
+            let Submarine {
+                position,
+                depth,
+                aim,
+                ..
+            } = step.submarine;
+            writeln!(
+                out,
+                "{},{},{},{},{}",
+                step.index + 1,
+                step.line,
+                position,
+                depth,
+                aim
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// A plot of depth against position, looking at the course from the
+    /// side, which fits in `width` by `height` characters.
+    pub fn side_view(&self, width: usize, height: usize) -> SideView<'_> {
+        SideView {
+            trajectory: self,
+            width,
+            height,
+        }
+    }
+}
+
+pub struct SideView<'a> {
+    trajectory: &'a Trajectory,
+    width: usize,
+    height: usize,
+}
+
+// Scale `value` in `from` to a cell in `0..cells`
+fn scale(value: i128, (low, high): (i128, i128), cells: usize) -> usize {
+    if high == low {
+        return 0;
+    }
+
+    // Far enough out, the difference between two values may not fit
+    let fraction = (value as f64 - low as f64) / (high as f64 - low as f64);
+    (fraction * (cells - 1) as f64) as usize
+}
+
+impl fmt::Display for SideView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let submarines: Vec<Submarine> = self.trajectory.submarines().collect();
+
+        // Always show the surface, which the course starts from
+        let bounds = |value: fn(&Submarine) -> i128| {
+            submarines
+                .iter()
+                .map(value)
+                .fold((0, 0), |(low, high), v| (low.min(v), high.max(v)))
+        };
+        let across = bounds(|submarine| submarine.position);
+        let down = bounds(|submarine| submarine.depth);
+
+        // Leave room for the labels
+        let labels = [down.0, 0, down.1].map(|depth| depth.to_string());
+        let margin = labels.iter().map(String::len).max().unwrap();
+        let (width, height) = (
+            self.width.saturating_sub(margin + 2).max(1),
+            self.height.max(1),
+        );
+
+        let mut grid = vec![vec![' '; width]; height];
+        let surface = scale(0, down, height);
+        grid[surface].fill('~');
+
+        // Join each place to the next, one cell at a time
+        let cell = |submarine: &Submarine| {
+            (
+                scale(submarine.position, across, width) as i64,
+                scale(submarine.depth, down, height) as i64,
+            )
+        };
+        for pair in submarines.windows(2) {
+            let ((x0, y0), (x1, y1)) = (cell(&pair[0]), cell(&pair[1]));
+            let cells = (x1 - x0).abs().max((y1 - y0).abs()).max(1);
+            for k in 0..=cells {
+                let x = x0 + (x1 - x0) * k / cells;
+                let y = y0 + (y1 - y0) * k / cells;
+                grid[y as usize][x as usize] = '*';
+            }
+        }
+        let (x, y) = cell(&submarines[0]);
+        grid[y as usize][x as usize] = 'o';
+
+        for (j, row) in grid.iter().enumerate() {
+            let label = if j == 0 {
+                &labels[0]
+            } else if j == height - 1 {
+                &labels[2]
+            } else if j == surface {
+                &labels[1]
+            } else {
+                ""
+            };
+            let row: String = row.iter().collect();
+            writeln!(f, "{:>margin$} |{}", label, row.trim_end())?;
+        }
+
+        writeln!(f, "{:>margin$} +{}", "", "-".repeat(width))?;
+        let (start, end) = (across.0.to_string(), across.1.to_string());
+        writeln!(
+            f,
+            "{:>margin$}  {}{:>pad$}",
+            "",
+            start,
+            end,
+            pad = width.saturating_sub(start.len()).max(end.len() + 1)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Aimed, Day02, Surface};
+    use common::Solution;
+
+    const SAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+";
+
+    #[test]
+    fn trajectory_exports() {
+        let course = Day02::parse(SAMPLE).unwrap();
+        let trajectory = course.trajectory(&Aimed, Surface::Error).unwrap();
+
+        let mut csv = Vec::new();
+        trajectory.write_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "\
+command,line,position,depth,aim
+0,,0,0,0
+1,1,5,0,0
+2,2,5,0,5
+3,3,13,40,5
+4,4,13,40,2
+5,5,13,40,10
+6,6,15,60,10
+"
+        );
+
+        assert_eq!(
+            trajectory.side_view(20, 4).to_string(),
+            // Not a string continuation, which would eat the leading spaces
+            concat!(
+                " 0 |o********~~~~~~~\n",
+                "   |         ****\n",
+                "   |             **\n",
+                "60 |               *\n",
+                "   +----------------\n",
+                "    0             15\n",
+            )
+        );
+    }
+}
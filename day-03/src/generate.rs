@@ -0,0 +1,19 @@
+use crate::Day03;
+use common::{Generate, Rng};
+
+impl Generate for Day03 {
+    // Distinct 12-bit numbers, at most 4096
+    const SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+
+        let mut numbers: Vec<u32> = (0..1 << 12).collect();
+        rng.shuffle(&mut numbers);
+
+        numbers[..size.min(numbers.len())]
+            .iter()
+            .map(|number| format!("{:012b}\n", number))
+            .collect()
+    }
+}
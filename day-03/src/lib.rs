@@ -0,0 +1,201 @@
+mod generate;
+mod matrix;
+
+use common::{parse_streamed, ParseError, Problem, Solution, Streaming};
+pub use matrix::{BitMatrix, Bits, Product};
+use std::cmp::Ordering;
+
+// Each bit of gamma is the most common bit in its column, and epsilon has
+// the others
+fn part_1_calculate_gamma_and_epsilon(report: &BitMatrix) -> (Bits, Bits) {
+    let gamma: Vec<bool> = (0..report.width())
+        .map(|column| report.ones(0..report.rows(), column) > report.rows() / 2)
+        .collect();
+    let epsilon = gamma.iter().map(|bit| !bit).collect();
+
+    (Bits(gamma), Bits(epsilon))
+}
+
+// Narrow the rows down a column at a time, from the left, keeping those
+// with the bit which `keep` picks given the counts of ones and zeros
+fn rating(report: &BitMatrix, keep: fn(usize, usize) -> Option<bool>) -> Bits {
+    let mut rows: Vec<usize> = (0..report.rows()).collect();
+
+    for column in 0..report.width() {
+        if rows.len() == 1 {
+            break;
+        }
+
+        let ones = report.ones(rows.iter().copied(), column);
+        if let Some(bit) = keep(ones, rows.len() - ones) {
+            rows.retain(|&row| report.bit(row, column) == bit);
+        }
+    }
+
+    report.row(rows[0])
+}
+
+fn part_2_calculate_oxgen_and_scrub(report: &BitMatrix) -> (Bits, Bits) {
+    let oxgen = rating(report, |ones, zeros| match ones.cmp(&zeros) {
+        Ordering::Greater => Some(true),
+        Ordering::Equal => Some(true),
+        Ordering::Less => Some(false),
+    });
+
+    let scrub = rating(report, |ones, zeros| {
+        // Every row has the same bit here, so none is less common
+        if ones == 0 || zeros == 0 {
+            return None;
+        }
+
+        match ones.cmp(&zeros) {
+            Ordering::Greater => Some(false),
+            Ordering::Equal => Some(false),
+            Ordering::Less => Some(true),
+        }
+    });
+
+    (oxgen, scrub)
+}
+
+#[derive(Debug, Default)]
+pub struct Day03 {
+    report: BitMatrix,
+}
+
+impl Solution for Day03 {
+    type Part1 = Product;
+    type Part2 = Product;
+
+    fn parse(input: &str) -> Result<Day03, ParseError> {
+        parse_streamed(input)
+    }
+
+    fn part_1(&self) -> Product {
+        let (gamma, epsilon) = part_1_calculate_gamma_and_epsilon(&self.report);
+
+        gamma.times(&epsilon)
+    }
+
+    fn part_2(&self) -> Product {
+        let (oxgen, scrub) = part_2_calculate_oxgen_and_scrub(&self.report);
+
+        oxgen.times(&scrub)
+    }
+}
+
+impl Streaming for Day03 {
+    fn parse_line(&mut self, line: &str) -> Result<(), ParseError> {
+        self.report.push_row(line)?;
+
+        Ok(())
+    }
+
+    fn finish(self, lines: usize) -> Result<Day03, ParseError> {
+        if self.report.rows() == 0 {
+            return Err(Problem::after_lines(lines, "expected a binary number").into());
+        }
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Answer, Generate, Rng};
+    use proptest::prelude::*;
+
+    const SAMPLE: &str = "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010
+";
+
+    // Filter the lines as text, keeping those with the bit `keep` picks
+    // from the counts of ones and zeros, while there is a choice
+    fn brute_force_rating(lines: &[&str], keep: fn(usize, usize) -> char) -> u64 {
+        let mut lines = lines.to_vec();
+        let mut column = 0;
+        while lines.len() > 1 {
+            let ones = lines
+                .iter()
+                .filter(|line| line.as_bytes()[column] == b'1')
+                .count();
+            let zeros = lines.len() - ones;
+            if ones > 0 && zeros > 0 {
+                let bit = keep(ones, zeros) as u8;
+                lines.retain(|line| line.as_bytes()[column] == bit);
+            }
+            column += 1;
+        }
+
+        u64::from_str_radix(lines[0], 2).unwrap()
+    }
+
+    fn brute_force(input: &str) -> (u128, u128) {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = lines[0].len();
+
+        let mut gamma = 0u64;
+        for column in 0..width {
+            let ones = lines
+                .iter()
+                .filter(|line| line.as_bytes()[column] == b'1')
+                .count();
+            gamma = gamma << 1 | (ones * 2 > lines.len()) as u64;
+        }
+        let epsilon = !gamma & (u64::MAX >> (64 - width));
+
+        let oxgen = brute_force_rating(&lines, |ones, zeros| if ones >= zeros { '1' } else { '0' });
+        let scrub = brute_force_rating(&lines, |ones, zeros| if ones < zeros { '1' } else { '0' });
+
+        (
+            u128::from(gamma) * u128::from(epsilon),
+            u128::from(oxgen) * u128::from(scrub),
+        )
+    }
+
+    #[test]
+    fn leading_zero_columns() {
+        let padded: String = SAMPLE.lines().map(|line| format!("0{}\n", line)).collect();
+        let solution = Day03::parse(&padded).unwrap();
+
+        // Gamma is 010110 and epsilon 101001, and the ratings are as before
+        assert_eq!(Answer::from(solution.part_1()), Answer::Number(22 * 41));
+        assert_eq!(Answer::from(solution.part_2()), Answer::Number(230));
+
+        assert!(Day03::parse("0101\n011\n").is_err());
+    }
+
+    proptest! {
+        // The same prefix on every line adds columns where every bit is
+        // the same, and takes the numbers past 32 bits
+        #[test]
+        fn matches_brute_force(
+            seed in any::<u64>(),
+            size in 1..200usize,
+            prefix in "[01]{0,48}",
+        ) {
+            let generated = Day03::generate(&mut Rng::new(seed), size);
+            let input: String = generated
+                .lines()
+                .map(|line| format!("{}{}\n", prefix, line))
+                .collect();
+            let day = Day03::parse(&input).unwrap();
+            let (part_1, part_2) = brute_force(&input);
+
+            prop_assert_eq!(Answer::from(day.part_1()), Answer::Number(part_1 as i128));
+            prop_assert_eq!(Answer::from(day.part_2()), Answer::Number(part_2 as i128));
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use common::{Answer, Problem};
+use std::fmt;
+
+const WORD: usize = u64::BITS as usize;
+
+/// Rows of bits which are all the same width, such as the lines of a
+/// diagnostic report. Columns count from the left, so column 0 holds the
+/// most significant bit of each row.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BitMatrix {
+    width: usize,
+    rows: usize,
+    // Each row packed into whole words, leftmost bit highest
+    words: Vec<u64>,
+}
+
+impl BitMatrix {
+    /// The number of bits in each row, taken from the first row pushed.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn words_per_row(&self) -> usize {
+        self.width.div_ceil(WORD)
+    }
+
+    /// Add a row written as binary digits, which must be as wide as the
+    /// rows before it.
+    pub fn push_row(&mut self, line: &str) -> Result<(), Problem> {
+        if let Some((j, c)) = line.char_indices().find(|&(_, c)| c != '0' && c != '1') {
+            return Err(Problem::new(
+                1,
+                j + 1,
+                line,
+                &c.to_string(),
+                "expected a binary digit",
+            ));
+        }
+        if line.is_empty() {
+            return Err(Problem::new(1, 1, line, "", "expected a binary number"));
+        }
+        if self.rows == 0 {
+            self.width = line.len();
+        } else if line.len() != self.width {
+            return Err(Problem::new(
+                1,
+                1,
+                line,
+                line,
+                format!(
+                    "expected {} bits, as on the first line, but found {}",
+                    self.width,
+                    line.len()
+                ),
+            ));
+        }
+
+        let start = self.words.len();
+        self.words.resize(start + self.words_per_row(), 0);
+        for (column, digit) in line.bytes().enumerate() {
+            if digit == b'1' {
+                self.words[start + column / WORD] |= 1 << (WORD - 1 - column % WORD);
+            }
+        }
+        self.rows += 1;
+
+        Ok(())
+    }
+
+    pub fn bit(&self, row: usize, column: usize) -> bool {
+        let word = self.words[row * self.words_per_row() + column / WORD];
+
+        word & 1 << (WORD - 1 - column % WORD) != 0
+    }
+
+    pub fn row(&self, row: usize) -> Bits {
+        Bits(
+            (0..self.width)
+                .map(|column| self.bit(row, column))
+                .collect(),
+        )
+    }
+
+    /// How many of the given rows have a one in `column`.
+    pub fn ones(&self, rows: impl Iterator<Item = usize>, column: usize) -> usize {
+        rows.filter(|&row| self.bit(row, column)).count()
+    }
+}
+
+/// A binary number of any width, most significant bit first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bits(pub Vec<bool>);
+
+impl Bits {
+    // The value as 32-bit limbs, least significant first
+    fn limbs(&self) -> Vec<u32> {
+        let mut limbs = vec![0; self.0.len().div_ceil(32)];
+        for (j, &bit) in self.0.iter().rev().enumerate() {
+            if bit {
+                limbs[j / 32] |= 1 << (j % 32);
+            }
+        }
+
+        limbs
+    }
+
+    /// Multiply two numbers, however wide they are.
+    pub fn times(&self, other: &Bits) -> Product {
+        let (a, b) = (self.limbs(), other.limbs());
+        let mut limbs = vec![0u32; a.len() + b.len()];
+
+        for (i, &x) in a.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &y) in b.iter().enumerate() {
+                let sum = u64::from(x) * u64::from(y) + u64::from(limbs[i + j]) + carry;
+                limbs[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            limbs[i + b.len()] = carry as u32;
+        }
+
+        Product(limbs)
+    }
+}
+
+impl fmt::Display for Bits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0
+            .iter()
+            .try_for_each(|&bit| write!(f, "{}", if bit { '1' } else { '0' }))
+    }
+}
+
+/// A product of two binary numbers, which may be too big for any integer
+/// type, as 32-bit limbs, least significant first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Product(Vec<u32>);
+
+impl Product {
+    fn to_i128(&self) -> Option<i128> {
+        let mut value: i128 = 0;
+        for &limb in self.0.iter().rev() {
+            value = value.checked_mul(1 << 32)?.checked_add(i128::from(limb))?;
+        }
+
+        Some(value)
+    }
+}
+
+impl fmt::Display for Product {
+    // Divide by a billion at a time, giving nine decimal digits each time
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const BILLION: u64 = 1_000_000_000;
+        let mut limbs = self.0.clone();
+        let mut chunks = Vec::new();
+
+        loop {
+            let mut remainder = 0u64;
+            for limb in limbs.iter_mut().rev() {
+                let value = remainder << 32 | u64::from(*limb);
+                *limb = (value / BILLION) as u32;
+                remainder = value % BILLION;
+            }
+            chunks.push(remainder);
+            while limbs.last() == Some(&0) {
+                limbs.pop();
+            }
+            if limbs.is_empty() {
+                break;
+            }
+        }
+
+        write!(f, "{}", chunks.pop().unwrap())?;
+        chunks
+            .iter()
+            .rev()
+            .try_for_each(|chunk| write!(f, "{:09}", chunk))
+    }
+}
+
+impl From<Product> for Answer {
+    fn from(product: Product) -> Self {
+        match product.to_i128() {
+            Some(number) => Answer::Number(number),
+            None => Answer::Text(product.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wide_rows() {
+        let mut matrix = BitMatrix::default();
+        let wide = format!("1{}1", "0".repeat(98));
+        matrix.push_row(&wide).unwrap();
+        matrix.push_row(&"0".repeat(100)).unwrap();
+
+        assert_eq!(matrix.width(), 100);
+        assert_eq!(matrix.row(0).to_string(), wide);
+        assert!(matrix.bit(0, 99) && !matrix.bit(0, 64) && !matrix.bit(1, 0));
+        assert!(matrix.push_row("101").is_err());
+
+        // (2^99 + 1)^2 = 2^198 + 2^100 + 1
+        let row = matrix.row(0);
+        assert_eq!(
+            row.times(&row).to_string(),
+            "401734511064747568885490523086558301230778977847194912030721"
+        );
+        assert_eq!(
+            Answer::from(Bits(vec![true, false, true]).times(&Bits(vec![true; 3]))),
+            Answer::Number(35)
+        );
+    }
+}
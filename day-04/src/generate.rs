@@ -0,0 +1,29 @@
+use crate::Day04;
+use common::{Generate, Rng};
+use std::fmt::Write;
+
+impl Generate for Day04 {
+    // Boards
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+
+        let mut numbers: Vec<u32> = (0..100).collect();
+        rng.shuffle(&mut numbers);
+        let draws: Vec<String> = numbers.iter().map(|number| number.to_string()).collect();
+        let mut result = draws.join(",");
+        result.push('\n');
+
+        for _ in 0..size {
+            rng.shuffle(&mut numbers);
+            result.push('\n');
+            for row in numbers[..25].chunks(5) {
+                let row: Vec<String> = row.iter().map(|number| format!("{:>2}", number)).collect();
+                writeln!(result, "{}", row.join(" ")).unwrap();
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,176 @@
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+mod generate;
+
+use common::{capture, NoAnswer, ParseError, Problem, Solution};
+use pest::Parser;
+
+#[derive(Parser)]
+#[grammar = "../../common/src/numbers.pest"]
+#[grammar = "bingo.pest"]
+struct BingoParser;
+
+#[derive(Debug, Clone)]
+struct Board {
+    targets: Vec<u32>,
+    marks: Vec<bool>,
+    result: Option<(usize, u32, u64)>,
+}
+
+impl Board {
+    fn new(targets: Vec<u32>) -> Board {
+        let marks: Vec<bool> = vec![false; targets.len()];
+        let result = None;
+
+        Board {
+            targets,
+            marks,
+            result,
+        }
+    }
+
+    fn play_round(&mut self, draw_index: usize, draw: u32) {
+        // If this board already won, don't play it any more
+        if self.result.is_some() {
+            return;
+        }
+
+        // Mark the draw, if found in targets for this board
+        for j in 0..self.targets.len() {
+            if self.targets[j] == draw {
+                self.marks[j] = true;
+                break;
+            }
+        }
+
+        // Check whether this board is now a winner
+        let groupings = vec![
+            [0, 1, 2, 3, 4],
+            [5, 6, 7, 8, 9],
+            [10, 11, 12, 13, 14],
+            [15, 16, 17, 18, 19],
+            [20, 21, 22, 23, 24],
+            [0, 5, 10, 15, 20],
+            [1, 6, 11, 16, 21],
+            [2, 7, 12, 17, 22],
+            [3, 8, 13, 18, 23],
+            [4, 9, 14, 19, 24],
+        ];
+
+        for &grouping in groupings.iter() {
+            if grouping.iter().all(|&x| self.marks[x]) {
+                let mut unmarked_sum = 0;
+                for j in 0..self.marks.len() {
+                    if !self.marks[j] {
+                        unmarked_sum += u64::from(self.targets[j]);
+                    }
+                }
+                self.result = Some((draw_index, draw, unmarked_sum));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    draws: Vec<u32>,
+    boards: Vec<Board>,
+}
+
+impl State {
+    fn new() -> State {
+        let draws = Vec::new();
+        let boards = Vec::new();
+
+        State { draws, boards }
+    }
+
+    fn parse_input(&mut self, input: &str) -> Result<(), ParseError> {
+        let pairs = BingoParser::parse(Rule::main, input)?;
+
+        for pair in pairs {
+            let rule = pair.as_rule();
+
+            match rule {
+                Rule::draws => {
+                    self.draws = capture(pair)?;
+                }
+                Rule::board => {
+                    self.boards.push(Board::new(capture(pair)?));
+                }
+                Rule::EOI => {}
+                _ => {
+                    let message = format!("unexpected {:?}", rule);
+                    return Err(Problem::at_span(pair.as_span(), message).into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn play(&mut self) {
+        for (draw_index, &draw) in self.draws.iter().enumerate() {
+            for board in self.boards.iter_mut() {
+                board.play_round(draw_index, draw);
+            }
+        }
+    }
+
+    // The final scores of the first board to win, and of the last, taking
+    // the first board listed when several win at once
+    fn scores(&self) -> Result<(u64, u64), NoAnswer> {
+        let results = || self.boards.iter().filter_map(|board| board.result);
+        let no_winner = || NoAnswer::new("no board wins with these draws");
+        let score = |(_, winning_draw, unmarked_sum): (usize, u32, u64)| {
+            u64::from(winning_draw) * unmarked_sum
+        };
+
+        let first = results()
+            .min_by_key(|&(round, _, _)| round)
+            .ok_or_else(no_winner)?;
+        let last = results()
+            .rev()
+            .max_by_key(|&(round, _, _)| round)
+            .ok_or_else(no_winner)?;
+
+        Ok((score(first), score(last)))
+    }
+}
+
+#[derive(Debug)]
+pub struct Day04 {
+    state: State,
+}
+
+impl Solution for Day04 {
+    type Part1 = Result<u64, NoAnswer>;
+    type Part2 = Result<u64, NoAnswer>;
+
+    fn parse(input: &str) -> Result<Day04, ParseError> {
+        let mut state = State::new();
+        state.parse_input(input)?;
+
+        Ok(Day04 { state })
+    }
+
+    fn part_1(&self) -> Result<u64, NoAnswer> {
+        let mut state = self.state.clone();
+        state.play();
+
+        let (first_score, _) = state.scores()?;
+
+        Ok(first_score)
+    }
+
+    fn part_2(&self) -> Result<u64, NoAnswer> {
+        let mut state = self.state.clone();
+        state.play();
+
+        let (_, last_score) = state.scores()?;
+
+        Ok(last_score)
+    }
+}
@@ -0,0 +1,39 @@
+use crate::Day05;
+use common::{Generate, Rng};
+use std::fmt::Write;
+
+impl Generate for Day05 {
+    // Lines of vents
+    const SIZE: usize = 500;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut result = String::new();
+
+        for _ in 0..size {
+            let (x1, y1) = (rng.range(10, 980), rng.range(10, 980));
+            let (dx, dy) = match rng.below(3) {
+                0 => (1, 0),
+                1 => (0, 1),
+                _ => (if rng.chance(50) { 1 } else { -1 }, 1),
+            };
+            // Stay on the 0..=990 square, which keeps diagonals at 45 degrees
+            let room = |from: i64, d: i64| match d {
+                1 => 990 - from,
+                -1 => from,
+                _ => i64::MAX,
+            };
+            let length = rng.range(1, 500).min(room(x1, dx)).min(room(y1, dy));
+            writeln!(
+                result,
+                "{},{} -> {},{}",
+                x1,
+                y1,
+                x1 + dx * length,
+                y1 + dy * length
+            )
+            .unwrap();
+        }
+
+        result
+    }
+}
@@ -0,0 +1,257 @@
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+mod generate;
+
+use common::{capture, parse_streamed, ParseError, Problem, Solution, Streaming};
+use pest::Parser;
+use std::collections::HashMap;
+
+#[derive(Parser)]
+#[grammar = "../../common/src/numbers.pest"]
+#[grammar = "lines.pest"]
+struct LineParser;
+
+// Every point a line covers is counted, so lines can't reach too far out
+const MAX_COORDINATE: u32 = 4095;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[derive(Debug, Clone)]
+struct Line {
+    x1: u32,
+    y1: u32,
+    x2: u32,
+    y2: u32,
+}
+
+impl Line {
+    fn points(&self, part_2_flag: bool) -> Vec<Point> {
+        let mut points = Vec::new();
+
+        // Horizontal lines
+        if self.y1 == self.y2 {
+            if self.x1 > self.x2 {
+                for x in self.x2..=self.x1 {
+                    points.push(Point { x, y: self.y1 });
+                }
+            } else {
+                for x in self.x1..=self.x2 {
+                    points.push(Point { x, y: self.y1 });
+                }
+            }
+        }
+
+        // Vertical lines
+        if self.x1 == self.x2 {
+            if self.y1 > self.y2 {
+                for y in self.y2..=self.y1 {
+                    points.push(Point { x: self.x1, y });
+                }
+            } else {
+                for y in self.y1..=self.y2 {
+                    points.push(Point { x: self.x1, y });
+                }
+            }
+        }
+
+        // Ignore diagonal lines for part 1
+        if !part_2_flag {
+            return points;
+        }
+
+        // Diagonal lines
+        if self.x1 > self.x2 && self.y1 != self.y2 {
+            if self.y1 > self.y2 {
+                for delta in 0..=(self.x1 - self.x2) {
+                    points.push(Point {
+                        x: self.x1 - delta,
+                        y: self.y1 - delta,
+                    });
+                }
+            } else {
+                for delta in 0..=(self.x1 - self.x2) {
+                    points.push(Point {
+                        x: self.x1 - delta,
+                        y: self.y1 + delta,
+                    });
+                }
+            }
+        }
+        if self.x2 > self.x1 && self.y1 != self.y2 {
+            if self.y1 > self.y2 {
+                for delta in 0..=(self.x2 - self.x1) {
+                    points.push(Point {
+                        x: self.x1 + delta,
+                        y: self.y1 - delta,
+                    });
+                }
+            } else {
+                for delta in 0..=(self.x2 - self.x1) {
+                    points.push(Point {
+                        x: self.x1 + delta,
+                        y: self.y1 + delta,
+                    });
+                }
+            }
+        }
+
+        points
+    }
+}
+
+// How many vent lines cover each point, kept up to date as lines are read
+// rather than keeping the lines
+#[derive(Debug, Default)]
+struct State {
+    straight_coverages: HashMap<Point, u32>,
+    coverages: HashMap<Point, u32>,
+}
+
+impl State {
+    fn parse_line(&mut self, input: &str) -> Result<(), ParseError> {
+        let pair = LineParser::parse(Rule::main, input)?.next().unwrap();
+        let span = pair.as_span();
+        let spans: Vec<_> = pair
+            .clone()
+            .into_inner()
+            .map(|value| value.as_span())
+            .collect();
+
+        let (x1, y1, x2, y2) = capture(pair)?;
+        for (span, value) in spans.into_iter().zip([x1, y1, x2, y2]) {
+            if value > MAX_COORDINATE {
+                let message = format!("expected a coordinate of at most {}", MAX_COORDINATE);
+                return Err(Problem::at_span(span, message).into());
+            }
+        }
+        let line = Line { x1, y1, x2, y2 };
+
+        // Vents only run straight or at 45 degrees
+        if x1 != x2 && y1 != y2 && x1.abs_diff(x2) != y1.abs_diff(y2) {
+            let message = "expected a horizontal, vertical or 45 degree line";
+            return Err(Problem::at_span(span, message).into());
+        }
+
+        for point in line.points(false) {
+            *self.straight_coverages.entry(point).or_insert(0) += 1;
+        }
+        for point in line.points(true) {
+            *self.coverages.entry(point).or_insert(0) += 1;
+        }
+
+        Ok(())
+    }
+
+    fn overlaps(&self, part_2_flag: bool) -> u32 {
+        let coverages = if part_2_flag {
+            &self.coverages
+        } else {
+            &self.straight_coverages
+        };
+
+        coverages
+            .values()
+            .filter(|&&coverage| coverage >= 2)
+            .count() as u32
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Day05 {
+    state: State,
+}
+
+impl Solution for Day05 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Day05, ParseError> {
+        parse_streamed(input)
+    }
+
+    fn part_1(&self) -> u32 {
+        self.state.overlaps(false)
+    }
+
+    fn part_2(&self) -> u32 {
+        self.state.overlaps(true)
+    }
+}
+
+impl Streaming for Day05 {
+    fn parse_line(&mut self, line: &str) -> Result<(), ParseError> {
+        self.state.parse_line(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Generate, Rng};
+    use proptest::prelude::*;
+
+    // Walk each line a point at a time across a grid of counts
+    fn brute_force(input: &str, diagonals: bool) -> u32 {
+        let mut counts = vec![vec![0u32; 1000]; 1000];
+
+        for line in input.lines() {
+            let numbers: Vec<i64> = line
+                .split(|ch: char| !ch.is_ascii_digit())
+                .filter(|part| !part.is_empty())
+                .map(|part| part.parse().unwrap())
+                .collect();
+            let (x1, y1, x2, y2) = (numbers[0], numbers[1], numbers[2], numbers[3]);
+            if !diagonals && x1 != x2 && y1 != y2 {
+                continue;
+            }
+
+            let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+            let (mut x, mut y) = (x1, y1);
+            loop {
+                counts[y as usize][x as usize] += 1;
+                if (x, y) == (x2, y2) {
+                    break;
+                }
+                x += dx;
+                y += dy;
+            }
+        }
+
+        counts.iter().flatten().filter(|&&count| count >= 2).count() as u32
+    }
+
+    #[test]
+    fn crooked_lines() {
+        for input in ["0,1 -> 5,0\n", "0,0 -> 3,1\n"] {
+            let error = Day05::parse(input).unwrap_err();
+            assert_eq!(error.problems.len(), 1);
+            assert_eq!(error.problems[0].line, 1);
+        }
+    }
+
+    #[test]
+    fn distant_lines() {
+        let error = Day05::parse("0,0 -> 4000000000,0\n").unwrap_err();
+        assert_eq!(error.problems.len(), 1);
+        assert_eq!(error.problems[0].column, 8);
+        assert_eq!(error.problems[0].span, "4000000000");
+        assert!(Day05::parse("0,0 -> 4095,0\n").is_ok());
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(seed in any::<u64>(), size in 1..50usize) {
+            let input = Day05::generate(&mut Rng::new(seed), size);
+            let day = Day05::parse(&input).unwrap();
+
+            prop_assert_eq!(day.part_1(), brute_force(&input, false));
+            prop_assert_eq!(day.part_2(), brute_force(&input, true));
+        }
+    }
+}
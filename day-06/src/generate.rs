@@ -0,0 +1,15 @@
+use crate::Day06;
+use common::{Generate, Rng};
+
+impl Generate for Day06 {
+    // Lanternfish
+    const SIZE: usize = 300;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+
+        let fishes: Vec<String> = (0..size).map(|_| rng.range(1, 5).to_string()).collect();
+
+        format!("{}\n", fishes.join(","))
+    }
+}
@@ -0,0 +1,162 @@
+mod generate;
+
+use common::{parse_separated, ParseError, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+struct State {
+    day: u64,
+    fishes: HashMap<u64, u64>,
+}
+
+impl State {
+    fn new(input: &str) -> Result<State, ParseError> {
+        let day = 0;
+        let mut fishes = HashMap::new();
+        parse_separated::<u64>(input.trim(), ',')?
+            .into_iter()
+            .for_each(|x| {
+                let f = fishes.entry(x).or_insert(0);
+                *f += 1;
+            });
+
+        Ok(State { day, fishes })
+    }
+
+    fn day(&mut self) {
+        let mut updated_fishes = HashMap::new();
+
+        for (&days, &quantity) in self.fishes.iter() {
+            match days {
+                0 => {
+                    let uf = updated_fishes.entry(6).or_insert(0);
+                    *uf += quantity;
+                    let uf = updated_fishes.entry(8).or_insert(0);
+                    *uf += quantity;
+                }
+                _ => {
+                    let uf = updated_fishes.entry(days - 1).or_insert(0);
+                    *uf += quantity;
+                }
+            }
+        }
+
+        self.day += 1;
+        self.fishes = updated_fishes;
+    }
+
+    fn quantity(&self) -> u64 {
+        let mut total_quantity = 0;
+
+        for (_, &quantity) in self.fishes.iter() {
+            total_quantity += quantity;
+        }
+
+        total_quantity
+    }
+}
+
+#[derive(Debug)]
+pub struct Day06 {
+    state: State,
+}
+
+impl Solution for Day06 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Day06, ParseError> {
+        let state = State::new(input)?;
+
+        Ok(Day06 { state })
+    }
+
+    fn part_1(&self) -> u64 {
+        let mut state = self.state.clone();
+
+        for _ in 0..80 {
+            state.day();
+        }
+
+        state.quantity()
+    }
+
+    fn part_2(&self) -> u64 {
+        let mut state = self.state.clone();
+
+        for _ in 0..256 {
+            state.day();
+        }
+
+        state.quantity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Generate, Rng};
+    use proptest::prelude::*;
+
+    // Every fish on its own, as the puzzle first describes them
+    fn brute_force(input: &str, days: usize) -> u64 {
+        let mut fishes: Vec<u8> = input
+            .trim()
+            .split(',')
+            .map(|x| x.parse().unwrap())
+            .collect();
+
+        for _ in 0..days {
+            let mut newborns = 0;
+            for fish in fishes.iter_mut() {
+                if *fish == 0 {
+                    *fish = 6;
+                    newborns += 1;
+                } else {
+                    *fish -= 1;
+                }
+            }
+            fishes.extend(std::iter::repeat_n(8, newborns));
+        }
+
+        fishes.len() as u64
+    }
+
+    // Too many fish to keep one by one after 256 days, so count each
+    // fish's descendants instead, remembering the counts already found
+    fn descendants(timer: usize, days: usize, known: &mut HashMap<(usize, usize), u64>) -> u64 {
+        if timer >= days {
+            return 1;
+        }
+        if let Some(&count) = known.get(&(timer, days)) {
+            return count;
+        }
+
+        let left = days - timer - 1;
+        let count = descendants(6, left, known) + descendants(8, left, known);
+        known.insert((timer, days), count);
+
+        count
+    }
+
+    fn by_descendants(input: &str, days: usize) -> u64 {
+        let mut known = HashMap::new();
+
+        input
+            .trim()
+            .split(',')
+            .map(|x| descendants(x.parse().unwrap(), days, &mut known))
+            .sum()
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(seed in any::<u64>(), size in 1..10usize) {
+            let input = Day06::generate(&mut Rng::new(seed), size);
+            let day = Day06::parse(&input).unwrap();
+
+            prop_assert_eq!(day.part_1(), brute_force(&input, 80));
+            prop_assert_eq!(day.part_2(), by_descendants(&input, 256));
+        }
+    }
+}
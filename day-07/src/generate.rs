@@ -0,0 +1,17 @@
+use crate::Day07;
+use common::{Generate, Rng};
+
+impl Generate for Day07 {
+    // Crabs
+    const SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+
+        let crabs: Vec<String> = (0..size)
+            .map(|_| (rng.range(0, 40) * rng.range(0, 45)).to_string())
+            .collect();
+
+        format!("{}\n", crabs.join(","))
+    }
+}
@@ -0,0 +1,129 @@
+mod generate;
+
+use common::{parse_separated, ParseError, Solution};
+
+#[derive(Debug)]
+struct State {
+    subs: Vec<u32>,
+}
+
+impl State {
+    fn new(input: &str) -> Result<State, ParseError> {
+        let subs: Vec<u32> = parse_separated(input.trim(), ',')?;
+
+        Ok(State { subs })
+    }
+
+    fn calculate(&self, part_2_flag: bool) -> (u32, u32) {
+        let &min_sub = self.subs.iter().min().unwrap();
+        let &max_sub = self.subs.iter().max().unwrap();
+
+        let mut fuel_totals: Vec<(u32, u32)> = Vec::new();
+
+        for position in min_sub..=max_sub {
+            let mut fuel_total = 0;
+            for &sub in self.subs.iter() {
+                let distance = sub.abs_diff(position);
+                let fuel = self.compute_fuel(distance, part_2_flag);
+                fuel_total += fuel;
+            }
+            fuel_totals.push((position, fuel_total));
+        }
+
+        let (best_position, cheapest_fuel_total) = fuel_totals
+            .into_iter()
+            .min_by_key(|&(_position, fuel_total)| fuel_total)
+            .unwrap();
+
+        (best_position, cheapest_fuel_total)
+    }
+
+    fn compute_fuel(&self, distance: u32, part_2_flag: bool) -> u32 {
+        if part_2_flag {
+            distance * (distance + 1) / 2
+        } else {
+            distance
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Day07 {
+    state: State,
+}
+
+impl Solution for Day07 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Day07, ParseError> {
+        let state = State::new(input)?;
+
+        Ok(Day07 { state })
+    }
+
+    fn part_1(&self) -> u32 {
+        let (_best_position, cheapest_fuel_total) = self.state.calculate(false);
+
+        cheapest_fuel_total
+    }
+
+    fn part_2(&self) -> u32 {
+        let (_best_position, cheapest_fuel_total) = self.state.calculate(true);
+
+        cheapest_fuel_total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Generate, Rng};
+    use proptest::prelude::*;
+
+    // Every position, with the fuel for each step added up one at a time
+    fn brute_force(input: &str, part_2: bool) -> u32 {
+        let crabs: Vec<u32> = input
+            .trim()
+            .split(',')
+            .map(|x| x.parse().unwrap())
+            .collect();
+        let &furthest = crabs.iter().max().unwrap();
+
+        (0..=furthest)
+            .map(|position| {
+                crabs
+                    .iter()
+                    .map(|crab| {
+                        let distance = crab.abs_diff(position);
+                        if part_2 {
+                            (1..=distance).sum()
+                        } else {
+                            distance
+                        }
+                    })
+                    .sum()
+            })
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn single_crab() {
+        let day = Day07::parse("1700\n").unwrap();
+
+        assert_eq!(day.part_1(), 0);
+        assert_eq!(day.part_2(), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(seed in any::<u64>(), size in 1..50usize) {
+            let input = Day07::generate(&mut Rng::new(seed), size);
+            let day = Day07::parse(&input).unwrap();
+
+            prop_assert_eq!(day.part_1(), brute_force(&input, false));
+            prop_assert_eq!(day.part_2(), brute_force(&input, true));
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::Day08;
+use common::{Generate, Rng};
+use std::fmt::Write;
+
+impl Generate for Day08 {
+    // Entries
+    const SIZE: usize = 200;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const DIGITS: [&str; 10] = [
+            "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+        ];
+        let mut result = String::new();
+
+        for _ in 0..size {
+            let mut wires: Vec<char> = "abcdefg".chars().collect();
+            rng.shuffle(&mut wires);
+            let pattern = |rng: &mut Rng, digit: usize| {
+                let mut signals: Vec<char> = DIGITS[digit]
+                    .chars()
+                    .map(|ch| wires[ch as usize - 'a' as usize])
+                    .collect();
+                rng.shuffle(&mut signals);
+                signals.into_iter().collect::<String>()
+            };
+
+            let mut digits: Vec<usize> = (0..10).collect();
+            rng.shuffle(&mut digits);
+            let inputs: Vec<String> = digits.iter().map(|&digit| pattern(rng, digit)).collect();
+            let outputs: Vec<String> = (0..4)
+                .map(|_| {
+                    let digit = rng.below(10);
+                    pattern(rng, digit)
+                })
+                .collect();
+            writeln!(result, "{} | {}", inputs.join(" "), outputs.join(" ")).unwrap();
+        }
+
+        result
+    }
+}
@@ -0,0 +1,197 @@
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+mod generate;
+
+use common::{parse_streamed, ParseError, Problem, Solution, Streaming};
+use pest::iterators::Pair;
+use pest::Parser;
+use std::collections::HashSet;
+
+#[derive(Parser)]
+#[grammar = "notes.pest"]
+struct NotesParser;
+
+#[derive(Debug, Hash, PartialEq, Eq)]
+enum Signal {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+}
+
+// The number of digits each display shows
+const OUTPUTS: usize = 4;
+
+fn parse_signal_pattern(pattern: Pair<'_, Rule>) -> Result<HashSet<Signal>, Problem> {
+    use self::Signal::*;
+
+    let mut signals = HashSet::new();
+
+    for ch in pattern.as_str().chars() {
+        signals.insert(match ch {
+            'a' => A,
+            'b' => B,
+            'c' => C,
+            'd' => D,
+            'e' => E,
+            'f' => F,
+            'g' => G,
+            _ => {
+                let message = format!("unknown signal {:?}", ch);
+                return Err(Problem::at_span(pattern.as_span(), message));
+            }
+        });
+    }
+
+    Ok(signals)
+}
+
+#[derive(Debug)]
+struct Display {
+    inputs: Vec<HashSet<Signal>>,
+    outputs: Vec<HashSet<Signal>>,
+}
+
+impl Display {
+    // Read and decode a display, so that only displays which can be solved
+    // are accepted
+    fn new(input: &str) -> Result<(Display, u64), ParseError> {
+        let mut inputs = Vec::new();
+        let mut outputs = Vec::new();
+        let mut output_spans = Vec::new();
+
+        let pairs = NotesParser::parse(Rule::main, input)?;
+
+        for pair in pairs {
+            match pair.as_rule() {
+                Rule::input => inputs.push(parse_signal_pattern(pair)?),
+                Rule::output => {
+                    output_spans.push(pair.as_span());
+                    outputs.push(parse_signal_pattern(pair)?);
+                }
+                Rule::EOI => {}
+                rule => {
+                    let message = format!("unexpected {:?}", rule);
+                    return Err(Problem::at_span(pair.as_span(), message).into());
+                }
+            }
+        }
+
+        if outputs.len() != OUTPUTS {
+            let message = format!(
+                "expected {} output patterns, found {}",
+                OUTPUTS,
+                outputs.len()
+            );
+            return Err(Problem::new(1, 1, input, input, message).into());
+        }
+
+        let display = Display { inputs, outputs };
+        let Some(digits) = display.deduce_digits() else {
+            let message = "these signal patterns aren't the ten digits of a display";
+            return Err(Problem::new(1, 1, input, input, message).into());
+        };
+
+        let mut value = 0;
+        for (output, span) in display.outputs.iter().zip(output_spans) {
+            let Some(digit) = digits.iter().position(|digit| *digit == output) else {
+                let message = "this pattern isn't any of the digits in the signal patterns";
+                return Err(Problem::at_span(span, message).into());
+            };
+            value = value * 10 + digit as u64;
+        }
+
+        Ok((display, value))
+    }
+
+    fn deduce_digits(&self) -> Option<Vec<&HashSet<Signal>>> {
+        let one = self.inputs.iter().find(|x| x.len() == 2)?;
+        let seven = self.inputs.iter().find(|x| x.len() == 3)?;
+        let four = self.inputs.iter().find(|x| x.len() == 4)?;
+        let eight = self.inputs.iter().find(|x| x.len() == 7)?;
+
+        let three = self
+            .inputs
+            .iter()
+            .find(|x| x.len() == 5 && x.intersection(one).count() == 2)?;
+        let six = self
+            .inputs
+            .iter()
+            .find(|x| x.len() == 6 && x.intersection(one).count() == 1)?;
+        let five = self
+            .inputs
+            .iter()
+            .find(|x| x.len() == 5 && x.intersection(six).count() == 5)?;
+        let nine = self
+            .inputs
+            .iter()
+            .find(|x| x.len() == 6 && x.difference(four).count() == 2)?;
+        let zero = self.inputs.iter().find(|x| {
+            x.len() == 6 && x.intersection(four).count() == 3 && x.intersection(seven).count() == 3
+        })?;
+        let two = self
+            .inputs
+            .iter()
+            .find(|x| x.len() == 5 && x.intersection(four).count() == 2)?;
+
+        Some(vec![
+            zero, one, two, three, four, five, six, seven, eight, nine,
+        ])
+    }
+}
+
+// Each display is decoded as it's read, so only the totals are kept
+#[derive(Debug, Default)]
+struct State {
+    easy_digits: u64,
+    output_sum: u64,
+}
+
+impl State {
+    fn parse_line(&mut self, line: &str) -> Result<(), ParseError> {
+        let (display, value) = Display::new(line)?;
+
+        for output in display.outputs.iter() {
+            match output.len() {
+                2 | 3 | 4 | 7 => self.easy_digits += 1,
+                _ => (),
+            }
+        }
+        self.output_sum += value;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Day08 {
+    state: State,
+}
+
+impl Solution for Day08 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Day08, ParseError> {
+        parse_streamed(input)
+    }
+
+    fn part_1(&self) -> u64 {
+        self.state.easy_digits
+    }
+
+    fn part_2(&self) -> u64 {
+        self.state.output_sum
+    }
+}
+
+impl Streaming for Day08 {
+    fn parse_line(&mut self, line: &str) -> Result<(), ParseError> {
+        self.state.parse_line(line)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::Day09;
+use common::{Generate, Rng};
+
+impl Generate for Day09 {
+    // Width and height of the height map
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+
+        rng.char_map(size, size, |rng| {
+            if rng.chance(20) {
+                '9'
+            } else {
+                char::from(b'0' + rng.below(9) as u8)
+            }
+        })
+    }
+}
@@ -0,0 +1,103 @@
+mod generate;
+
+use common::{Grid, Neighborhood, ParseError, Solution};
+
+#[derive(Debug, Clone)]
+struct State {
+    points: Grid<u32>,
+    low_points: Vec<(usize, usize)>,
+}
+
+impl State {
+    fn new(input: &str) -> Result<State, ParseError> {
+        let points = Grid::parse(input, "a digit", |ch| ch.to_digit(10))?;
+        let low_points = Vec::new();
+
+        Ok(State { points, low_points })
+    }
+
+    fn find_low_points(&mut self) {
+        for ((x, y), &height) in self.points.iter() {
+            if self
+                .points
+                .neighbors((x, y), Neighborhood::Four)
+                .all(|neighbor| height < self.points[neighbor])
+            {
+                self.low_points.push((x, y));
+            }
+        }
+    }
+
+    fn risk_level_sum(&self) -> u32 {
+        self.low_points
+            .iter()
+            .map(|&position| self.points[position] + 1)
+            .sum()
+    }
+
+    fn find_basin_point_count(&self, low_point: (usize, usize)) -> u32 {
+        let mut count = 0;
+        let mut points_visited = Grid::new(self.points.width(), self.points.height(), false);
+        let mut basin_points: Vec<(usize, usize)> = vec![low_point];
+
+        while let Some(basin_point) = basin_points.pop() {
+            if points_visited[basin_point] {
+                continue;
+            }
+
+            points_visited[basin_point] = true;
+
+            if self.points[basin_point] == 9 {
+                continue;
+            }
+
+            count += 1;
+
+            basin_points.extend(self.points.neighbors(basin_point, Neighborhood::Four));
+        }
+
+        count
+    }
+
+    fn calculate_basins_product(&self) -> u32 {
+        let mut basin_products = Vec::new();
+
+        for &low_point in self.low_points.iter() {
+            basin_products.push(self.find_basin_point_count(low_point));
+        }
+
+        basin_products.sort_unstable();
+
+        basin_products.iter().rev().take(3).product()
+    }
+}
+
+#[derive(Debug)]
+pub struct Day09 {
+    state: State,
+}
+
+impl Solution for Day09 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Day09, ParseError> {
+        let state = State::new(input)?;
+
+        Ok(Day09 { state })
+    }
+
+    fn part_1(&self) -> u32 {
+        let mut state = self.state.clone();
+        state.find_low_points();
+
+        state.risk_level_sum()
+    }
+
+    fn part_2(&self) -> u32 {
+        let mut state = self.state.clone();
+        state.find_low_points();
+
+        state.calculate_basins_product()
+    }
+}
@@ -0,0 +1,55 @@
+use crate::Day10;
+use common::{Generate, Rng};
+
+// Even lines are incomplete, and odd lines are corrupted, so that there is
+// an odd number of incomplete lines with a middle score
+impl Generate for Day10 {
+    // Lines
+    const SIZE: usize = 99;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+
+        const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+        let mut result = String::new();
+
+        // With an even number of even lines, the last of them is corrupted
+        // too
+        let last_incomplete = if size > 0 && size.div_ceil(2).is_multiple_of(2) {
+            Some((size - 1) / 2 * 2)
+        } else {
+            None
+        };
+
+        for line in 0..size {
+            let mut stack = Vec::new();
+            let length = rng.range(80, 110);
+            let corrupt = line % 2 == 1 || Some(line) == last_incomplete;
+            let mut corrupted = false;
+
+            for j in 0..length {
+                if stack.is_empty() || (rng.chance(55) && j < length - 10) {
+                    let (open, close) = PAIRS[rng.below(4)];
+                    stack.push(close);
+                    result.push(open);
+                } else if corrupt && !corrupted && j > length / 2 {
+                    let close = stack.pop().unwrap();
+                    let wrong = PAIRS
+                        .iter()
+                        .map(|&(_, close)| close)
+                        .find(|&ch| ch != close);
+                    result.push(wrong.unwrap());
+                    corrupted = true;
+                } else {
+                    result.push(stack.pop().unwrap());
+                }
+            }
+            if stack.is_empty() {
+                result.push('(');
+            }
+            result.push('\n');
+        }
+
+        result
+    }
+}
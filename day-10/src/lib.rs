@@ -0,0 +1,140 @@
+mod generate;
+
+use common::{parse_streamed, ParseError, Problem, Solution, Streaming};
+
+// What reading a line of brackets comes to: the score of the first
+// illegal closing bracket, or else the score of completing the line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Score {
+    SyntaxError(u64),
+    Completion(u64),
+}
+
+fn closing(open: char) -> char {
+    match open {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        _ => '>',
+    }
+}
+
+fn score_line(line: &str) -> Result<Score, String> {
+    let mut stack = Vec::new();
+
+    for ch in line.chars() {
+        match ch {
+            '(' | '[' | '{' | '<' => stack.push(ch),
+            // A closing bracket with nothing open is passed over
+            _ => {
+                if let Some(open) = stack.pop() {
+                    if closing(open) != ch {
+                        return Ok(Score::SyntaxError(match ch {
+                            ')' => 3,
+                            ']' => 57,
+                            '}' => 1197,
+                            _ => 25137,
+                        }));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut score: u64 = 0;
+    while let Some(open) = stack.pop() {
+        let points = match open {
+            '(' => 1,
+            '[' => 2,
+            '{' => 3,
+            _ => 4,
+        };
+        score = score
+            .checked_mul(5)
+            .and_then(|score| score.checked_add(points))
+            .ok_or("too many brackets are left open to score completing this line")?;
+    }
+
+    Ok(Score::Completion(score))
+}
+
+// Each line is scored as it's read, so only the scores are kept
+#[derive(Debug, Default)]
+pub struct Day10 {
+    syntax_error_score: u64,
+    completion_scores: Vec<u64>,
+}
+
+impl Solution for Day10 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Day10, ParseError> {
+        parse_streamed(input)
+    }
+
+    fn part_1(&self) -> u64 {
+        self.syntax_error_score
+    }
+
+    fn part_2(&self) -> u64 {
+        let mut scores = self.completion_scores.clone();
+        let middle = scores.len() / 2;
+
+        *scores.select_nth_unstable(middle).1
+    }
+}
+
+impl Streaming for Day10 {
+    fn parse_line(&mut self, line: &str) -> Result<(), ParseError> {
+        let mut problems = Vec::new();
+        for (x, ch) in line.chars().enumerate() {
+            if !"()[]{}<>".contains(ch) {
+                problems.push(Problem::new(
+                    1,
+                    x + 1,
+                    line,
+                    &ch.to_string(),
+                    format!("expected a bracket, found {:?}", ch),
+                ));
+            }
+        }
+        if !problems.is_empty() {
+            return Err(ParseError::new(problems));
+        }
+
+        match score_line(line).map_err(|message| Problem::new(1, 1, line, line, message))? {
+            Score::SyntaxError(score) => self.syntax_error_score += score,
+            Score::Completion(score) => self.completion_scores.push(score),
+        }
+
+        Ok(())
+    }
+
+    fn finish(self, lines: usize) -> Result<Day10, ParseError> {
+        if lines == 0 {
+            return Err(Problem::after_lines(lines, "expected a line of brackets").into());
+        }
+        if self.completion_scores.is_empty() {
+            let message = "expected an incomplete line, as every line is corrupted";
+            return Err(Problem::after_lines(lines, message).into());
+        }
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_line_corrupted() {
+        let error = Day10::parse("(]\n{)\n").unwrap_err();
+        assert_eq!(error.problems.len(), 1);
+        assert_eq!(error.problems[0].line, 3);
+
+        let solution = Day10::parse("(]\n[({\n").unwrap();
+        assert_eq!((solution.part_1(), solution.part_2()), (57, 82));
+    }
+}
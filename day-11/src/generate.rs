@@ -0,0 +1,24 @@
+use crate::{Day11, State};
+use common::{Generate, Rng};
+
+impl Generate for Day11 {
+    // Width and height of the grid of octopuses
+    const SIZE: usize = 10;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+
+        // Many grids settle into waves which never line up, so keep trying
+        // until one has an answer to part 2
+        loop {
+            let input = rng.char_map(size, size, |rng| char::from(b'1' + rng.below(9) as u8));
+            let Ok(state) = State::new(&input) else {
+                return input;
+            };
+
+            if state.synchronize(|_| {}).is_ok() {
+                return input;
+            }
+        }
+    }
+}
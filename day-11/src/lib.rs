@@ -0,0 +1,164 @@
+mod generate;
+
+use common::{Grid, Neighborhood, NoAnswer, ParseError, Recording, Simulation, Solution};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone)]
+struct State {
+    octopuses: Grid<u32>,
+    flash_count: u32,
+}
+
+impl State {
+    fn new(input: &str) -> Result<State, ParseError> {
+        let octopuses = Grid::parse(input, "a digit", |ch| ch.to_digit(10))?;
+        let flash_count = 0;
+
+        Ok(State {
+            octopuses,
+            flash_count,
+        })
+    }
+
+    // Octopuses which have just flashed are the brightest, and the rest
+    // glow brighter as their energy level rises
+    fn frame(&self) -> Grid<u8> {
+        self.octopuses.map(|&energy| match energy {
+            0 => 9,
+            _ => energy.min(9) as u8 - 1,
+        })
+    }
+
+    fn step(&mut self) -> usize {
+        for position in self.octopuses.positions() {
+            self.octopuses[position] += 1;
+        }
+
+        let mut flasheds = HashSet::new();
+
+        loop {
+            let mut new_flash = false;
+
+            for position in self.octopuses.positions() {
+                if self.octopuses[position] <= 9 {
+                    continue;
+                }
+
+                if !flasheds.insert(position) {
+                    continue;
+                }
+
+                new_flash = true;
+
+                for neighbor in self.octopuses.neighbors(position, Neighborhood::Eight) {
+                    self.octopuses[neighbor] += 1;
+                }
+            }
+
+            if !new_flash {
+                break;
+            }
+        }
+
+        for &position in flasheds.iter() {
+            self.octopuses[position] = 0;
+            self.flash_count += 1;
+        }
+
+        flasheds.len()
+    }
+
+    // Step until every octopus flashes at once, visiting the octopuses
+    // after each step, and give the number of steps taken. The octopuses
+    // may instead fall into a cycle of steps in which they never do, which
+    // is found by Brent's method, comparing each grid with one saved at the
+    // last power of two steps
+    fn synchronize(&self, mut visit: impl FnMut(&State)) -> Result<u32, NoAnswer> {
+        let mut state = self.clone();
+        let octopus_count = state.octopuses.width() * state.octopuses.height();
+        let mut saved = state.octopuses.clone();
+        let mut power = 1;
+        let mut length = 0;
+
+        let mut step_count = 0;
+        loop {
+            step_count += 1;
+
+            let flash_count = state.step();
+            visit(&state);
+            if flash_count == octopus_count {
+                return Ok(step_count);
+            }
+            if state.octopuses == saved {
+                return Err(NoAnswer::new("the octopuses never all flash at once"));
+            }
+
+            length += 1;
+            if length == power {
+                saved = state.octopuses.clone();
+                power *= 2;
+                length = 0;
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Day11 {
+    state: State,
+}
+
+impl Solution for Day11 {
+    type Part1 = u32;
+    type Part2 = Result<u32, NoAnswer>;
+
+    fn parse(input: &str) -> Result<Day11, ParseError> {
+        let state = State::new(input)?;
+
+        Ok(Day11 { state })
+    }
+
+    fn part_1(&self) -> u32 {
+        let mut state = self.state.clone();
+
+        for _ in 0..100 {
+            state.step();
+        }
+
+        state.flash_count
+    }
+
+    fn part_2(&self) -> Result<u32, NoAnswer> {
+        self.state.synchronize(|_| {})
+    }
+}
+
+// Every step until all of the octopuses flash at once
+impl Simulation for Day11 {
+    fn record(&self) -> Recording {
+        let mut recording = Recording::new(10);
+
+        // Octopuses which never all flash at once are recorded until their
+        // steps repeat
+        recording.push(self.state.frame());
+        let _ = self
+            .state
+            .synchronize(|state| recording.push(state.frame()));
+
+        recording
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn never_all_flash() {
+        let solution = Day11::parse("1\n2\n3\n").unwrap();
+        assert!(solution.part_2().is_err());
+
+        let solution = Day11::parse("9\n").unwrap();
+        assert_eq!(solution.part_2(), Ok(1));
+    }
+}
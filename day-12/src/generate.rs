@@ -0,0 +1,64 @@
+use crate::Day12;
+use common::{Generate, Rng};
+
+// Cave names as in personal puzzle inputs, numbered once they run out
+const SMALL: [&str; 7] = ["xq", "kt", "ud", "rn", "fw", "lp", "zs"];
+const BIG: [&str; 3] = ["HX", "TK", "OM"];
+
+fn cave_name(j: usize, big: bool) -> String {
+    let names: &[&str] = if big { &BIG } else { &SMALL };
+
+    if j < names.len() {
+        String::from(names[j])
+    } else {
+        format!("{}{}", names[j % names.len()], j / names.len())
+    }
+}
+
+impl Generate for Day12 {
+    // Small caves, other than start and end
+    const SIZE: usize = 7;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let small: Vec<String> = (0..size).map(|j| cave_name(j, false)).collect();
+        let big: Vec<String> = (0..size.div_ceil(3)).map(|j| cave_name(j, true)).collect();
+        let mut connections = Vec::new();
+
+        // Every small cave connects to a big cave, and big caves are never
+        // adjacent, so that the number of paths is finite
+        for (j, cave) in small.iter().enumerate() {
+            connections.push((big[j % big.len()].clone(), cave.clone()));
+        }
+        for cave in big.iter() {
+            connections.push((String::from("start"), cave.clone()));
+            connections.push((cave.clone(), String::from("end")));
+        }
+        if rng.chance(10) {
+            connections.push((String::from("start"), String::from("end")));
+        }
+        let ends = [String::from("start"), String::from("end")];
+        let target = connections.len() + size * 9 / 7;
+        let mut attempts = 0;
+        while connections.len() < target && attempts < 100 * target {
+            attempts += 1;
+            let a = &small[rng.below(small.len())];
+            let b = match rng.below(5) {
+                0 | 1 => &small[rng.below(small.len())],
+                2 | 3 => &big[rng.below(big.len())],
+                _ => &ends[rng.below(ends.len())],
+            };
+            let pair = (a.clone(), b.clone());
+            let reversed = (b.clone(), a.clone());
+            if a != b && !connections.contains(&pair) && !connections.contains(&reversed) {
+                connections.push(pair);
+            }
+        }
+        rng.shuffle(&mut connections);
+
+        connections
+            .iter()
+            .map(|(a, b)| format!("{}-{}\n", a, b))
+            .collect()
+    }
+}
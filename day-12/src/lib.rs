@@ -0,0 +1,228 @@
+mod generate;
+
+use common::{parse_lines, ParseError, Problem, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+enum Cave {
+    Start,
+    End,
+    Big(String),
+    Small(String),
+}
+
+impl Cave {
+    // A cave's name is all uppercase letters for a big cave, or all
+    // lowercase letters for a small one
+    fn parse(name: &str) -> Option<Cave> {
+        match name {
+            "start" => Some(Cave::Start),
+            "end" => Some(Cave::End),
+            _ if name.is_empty() => None,
+            _ if name.chars().all(|ch| ch.is_ascii_uppercase()) => {
+                Some(Cave::Big(String::from(name)))
+            }
+            _ if name.chars().all(|ch| ch.is_ascii_lowercase()) => {
+                Some(Cave::Small(String::from(name)))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    connections: HashMap<Cave, Vec<Cave>>,
+}
+
+impl State {
+    fn new(input: &str) -> Result<State, ParseError> {
+        let mut connections: HashMap<Cave, Vec<Cave>> = HashMap::new();
+
+        let pairs: Vec<(Cave, Cave)> = parse_lines(input, |line| {
+            let caves = line.split_once('-').and_then(|(a, b)| {
+                let pair = (Cave::parse(a)?, Cave::parse(b)?);
+                Some((a, b, pair))
+            });
+
+            match caves {
+                Some((a, b, pair)) => match pair {
+                    // A path could go back and forth between them forever
+                    (Cave::Big(_), Cave::Big(_)) => Err(Problem::new(
+                        1,
+                        1,
+                        line,
+                        line,
+                        format!(
+                            "big caves {} and {} are connected, so there are infinitely many paths",
+                            a, b
+                        ),
+                    )
+                    .into()),
+                    pair => Ok(pair),
+                },
+                None => Err(Problem::new(
+                    1,
+                    1,
+                    line,
+                    line,
+                    "expected a connection such as \"start-A\"",
+                )
+                .into()),
+            }
+        })?;
+        if !pairs
+            .iter()
+            .any(|(a, b)| *a == Cave::Start || *b == Cave::Start)
+        {
+            return Err(Problem::at_end(input, "expected a connection to the start cave").into());
+        }
+
+        for (cave_a, cave_b) in pairs {
+            let vec_a = connections.entry(cave_a.clone()).or_default();
+            vec_a.push(cave_b.clone());
+            let vec_b = connections.entry(cave_b).or_default();
+            vec_b.push(cave_a);
+        }
+
+        Ok(State { connections })
+    }
+
+    fn find_paths(&self, revisitable: bool) -> Vec<Vec<Cave>> {
+        let mut complete_paths: Vec<Vec<Cave>> = Vec::new();
+        let mut partial_paths: Vec<(Vec<Cave>, bool)> = Vec::new();
+
+        partial_paths.push((vec![Cave::Start], revisitable));
+
+        while let Some((mut partial_path, revisitable)) = partial_paths.pop() {
+            if let Some(previous_cave) = partial_path.pop() {
+                for cave in self.connections[&previous_cave].iter() {
+                    match cave {
+                        Cave::Start => {}
+                        Cave::Big(_) => {
+                            let mut new_partial_path = partial_path.clone();
+                            new_partial_path.push(previous_cave.clone());
+                            new_partial_path.push(cave.clone());
+                            partial_paths.push((new_partial_path, revisitable));
+                        }
+                        Cave::Small(_) => {
+                            let mut new_revisitable = revisitable;
+                            if partial_path.contains(cave) {
+                                if new_revisitable {
+                                    new_revisitable = false;
+                                } else {
+                                    continue;
+                                }
+                            }
+                            let mut new_partial_path = partial_path.clone();
+                            new_partial_path.push(previous_cave.clone());
+                            new_partial_path.push(cave.clone());
+                            partial_paths.push((new_partial_path, new_revisitable));
+                        }
+                        Cave::End => {
+                            let mut complete_path = partial_path.clone();
+                            complete_path.push(previous_cave.clone());
+                            complete_path.push(cave.clone());
+                            complete_paths.push(complete_path);
+                        }
+                    }
+                }
+            }
+        }
+
+        complete_paths
+    }
+}
+
+#[derive(Debug)]
+pub struct Day12 {
+    state: State,
+}
+
+impl Solution for Day12 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Day12, ParseError> {
+        let state = State::new(input)?;
+
+        Ok(Day12 { state })
+    }
+
+    fn part_1(&self) -> usize {
+        let complete_paths = self.state.find_paths(false);
+
+        complete_paths.len()
+    }
+
+    fn part_2(&self) -> usize {
+        let complete_paths = self.state.find_paths(true);
+
+        complete_paths.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Generate, Rng};
+    use proptest::prelude::*;
+
+    // Count the paths on from `cave` by recursion, rather than keeping a
+    // stack of partial paths
+    fn count_paths<'a>(
+        connections: &HashMap<&'a str, Vec<&'a str>>,
+        cave: &'a str,
+        visited: &mut Vec<&'a str>,
+        revisit: bool,
+    ) -> usize {
+        if cave == "end" {
+            return 1;
+        }
+
+        let mut count = 0;
+        for &next in connections[cave].iter() {
+            let small = next.chars().all(|ch| ch.is_lowercase());
+            let seen = small && visited.contains(&next);
+            if next == "start" || (seen && !revisit) {
+                continue;
+            }
+
+            visited.push(next);
+            count += count_paths(connections, next, visited, revisit && !seen);
+            visited.pop();
+        }
+
+        count
+    }
+
+    fn brute_force(input: &str, revisit: bool) -> usize {
+        let mut connections: HashMap<&str, Vec<&str>> = HashMap::new();
+        for line in input.lines() {
+            let (a, b) = line.split_once('-').unwrap();
+            connections.entry(a).or_default().push(b);
+            connections.entry(b).or_default().push(a);
+        }
+
+        count_paths(&connections, "start", &mut vec!["start"], revisit)
+    }
+
+    #[test]
+    fn adjacent_big_caves() {
+        let error = Day12::parse("start-A\nA-b\nb-B\nB-A\nB-end\n").unwrap_err();
+
+        assert_eq!(error.problems.len(), 1);
+        assert_eq!(error.problems[0].line, 4);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(seed in any::<u64>(), size in 1..6usize) {
+            let input = Day12::generate(&mut Rng::new(seed), size);
+            let day = Day12::parse(&input).unwrap();
+
+            prop_assert_eq!(day.part_1(), brute_force(&input, false));
+            prop_assert_eq!(day.part_2(), brute_force(&input, true));
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::Day13;
+use common::{Generate, Rng};
+use std::fmt::Write;
+
+// The dots include the far corner of the paper, since each fold must be
+// at or beyond the middle of what remains
+impl Generate for Day13 {
+    // Dots, besides the one in the far corner
+    const SIZE: usize = 800;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut result = String::from("1310,894\n");
+
+        for _ in 0..size {
+            let x = rng.range(0, 1310);
+            let y = rng.range(0, 894);
+            writeln!(result, "{},{}", x, y).unwrap();
+        }
+        result.push('\n');
+        for (axis, along) in [
+            ('x', 655),
+            ('y', 447),
+            ('x', 327),
+            ('y', 223),
+            ('x', 163),
+            ('y', 111),
+            ('x', 81),
+            ('y', 55),
+            ('x', 40),
+            ('y', 27),
+            ('y', 13),
+            ('y', 6),
+        ] {
+            writeln!(result, "fold along {}={}", axis, along).unwrap();
+        }
+
+        result
+    }
+}
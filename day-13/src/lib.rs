@@ -0,0 +1,239 @@
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+mod generate;
+
+use common::{capture, Grid, ParseError, Problem, Recording, Simulation, Solution};
+use pest::Parser;
+
+#[derive(Parser)]
+#[grammar = "../../common/src/numbers.pest"]
+#[grammar = "manual.pest"]
+struct ManualParser;
+
+// The paper is held whole, so dots can't be too far out
+const MAX_COORDINATE: usize = 4095;
+
+#[derive(Debug, Clone)]
+enum Fold {
+    X(usize),
+    Y(usize),
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+struct State {
+    dots: Vec<(usize, usize)>,
+    folds: Vec<Fold>,
+    paper: Vec<Vec<bool>>,
+    max_x: usize,
+    max_y: usize,
+}
+
+impl State {
+    fn new(input: &str) -> Result<State, ParseError> {
+        let mut dots = Vec::new();
+        let mut folds = Vec::new();
+        let mut fold_spans = Vec::new();
+
+        let pairs = ManualParser::parse(Rule::main, input)?;
+
+        for pair in pairs {
+            let rule = pair.as_rule();
+
+            match rule {
+                Rule::dot => {
+                    let spans: Vec<_> = pair
+                        .clone()
+                        .into_inner()
+                        .map(|value| value.as_span())
+                        .collect();
+                    let (x, y) = capture(pair)?;
+                    for (span, value) in spans.into_iter().zip([x, y]) {
+                        if value > MAX_COORDINATE {
+                            let message =
+                                format!("expected a coordinate of at most {}", MAX_COORDINATE);
+                            return Err(Problem::at_span(span, message).into());
+                        }
+                    }
+                    dots.push((x, y));
+                }
+                Rule::fold_x | Rule::fold_y => {
+                    let value = pair.clone().into_inner().next().unwrap();
+                    fold_spans.push(value.as_span());
+                    folds.push(match rule {
+                        Rule::fold_x => Fold::X(capture(pair)?),
+                        _ => Fold::Y(capture(pair)?),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        let max_x = dots
+            .iter()
+            .fold(0, |acc, &(x, _)| if x > acc { x } else { acc })
+            + 1;
+        let max_y = dots
+            .iter()
+            .fold(0, |acc, &(_, y)| if y > acc { y } else { acc })
+            + 1;
+
+        // Each fold has to be on the paper, and fold nothing past its
+        // far edge
+        let (mut width, mut height) = (max_x, max_y);
+        for (fold, span) in folds.iter().zip(fold_spans) {
+            let (along, extent, size, edge) = match *fold {
+                Fold::X(along) => (along, &mut width, "wide", "left"),
+                Fold::Y(along) => (along, &mut height, "tall", "top"),
+            };
+            if along >= *extent {
+                let message = format!(
+                    "this fold is off the paper, which is {} dots {} here",
+                    *extent, size
+                );
+                return Err(Problem::at_span(span, message).into());
+            }
+            if *extent - 1 - along > along {
+                let message = format!("this fold takes dots past the {} edge of the paper", edge);
+                return Err(Problem::at_span(span, message).into());
+            }
+            *extent = along;
+        }
+
+        let mut paper = vec![vec![false; max_x]; max_y];
+
+        for &(x, y) in dots.iter() {
+            paper[y][x] = true;
+        }
+
+        Ok(State {
+            dots,
+            folds,
+            paper,
+            max_x,
+            max_y,
+        })
+    }
+
+    fn paper_rows(&self) -> Vec<String> {
+        let mut rows = Vec::new();
+
+        for y in 0..self.max_y {
+            let mut row = String::new();
+            for x in 0..self.max_x {
+                row.push(if self.paper[y][x] { '#' } else { '.' });
+            }
+            rows.push(row);
+        }
+
+        rows
+    }
+
+    fn frame(&self) -> Grid<u8> {
+        let mut frame = Grid::new(self.max_x, self.max_y, 0);
+        for (x, y) in frame.positions() {
+            frame[(x, y)] = self.paper[y][x] as u8;
+        }
+
+        frame
+    }
+
+    fn fold(&mut self, fold: &Fold) {
+        match *fold {
+            Fold::X(along_x) => {
+                for y in 0..self.max_y {
+                    for x in 1..(self.max_x - along_x) {
+                        if self.paper[y][along_x + x] {
+                            self.paper[y][along_x - x] = true;
+                        }
+                    }
+                }
+                self.max_x = along_x;
+            }
+            Fold::Y(along_y) => {
+                for y in 1..(self.max_y - along_y) {
+                    for x in 0..self.max_x {
+                        if self.paper[along_y + y][x] {
+                            self.paper[along_y - y][x] = true;
+                        }
+                    }
+                }
+                self.max_y = along_y;
+            }
+        }
+    }
+
+    fn fold_paper(&mut self, part_1_flag: bool) {
+        let folds = self.folds.clone();
+
+        for fold in folds.iter() {
+            self.fold(fold);
+
+            if part_1_flag {
+                break;
+            }
+        }
+    }
+
+    fn count_dots(&self) -> u32 {
+        let mut count = 0;
+
+        for y in 0..self.max_y {
+            for x in 0..self.max_x {
+                if self.paper[y][x] {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+}
+
+#[derive(Debug)]
+pub struct Day13 {
+    state: State,
+}
+
+impl Solution for Day13 {
+    type Part1 = u32;
+    type Part2 = Vec<String>;
+
+    fn parse(input: &str) -> Result<Day13, ParseError> {
+        let state = State::new(input)?;
+
+        Ok(Day13 { state })
+    }
+
+    fn part_1(&self) -> u32 {
+        let mut state = self.state.clone();
+        state.fold_paper(true);
+
+        state.count_dots()
+    }
+
+    fn part_2(&self) -> Vec<String> {
+        let mut state = self.state.clone();
+        state.fold_paper(false);
+
+        state.paper_rows()
+    }
+}
+
+// The paper before folding, and after each fold
+impl Simulation for Day13 {
+    fn record(&self) -> Recording {
+        let mut state = self.state.clone();
+        let mut recording = Recording::new(2);
+
+        recording.push(state.frame());
+        for fold in self.state.folds.iter() {
+            state.fold(fold);
+            recording.push(state.frame());
+        }
+
+        recording
+    }
+}
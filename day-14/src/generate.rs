@@ -0,0 +1,22 @@
+use crate::Day14;
+use common::{Generate, Rng};
+use std::fmt::Write;
+
+impl Generate for Day14 {
+    // Elements in the polymer template
+    const SIZE: usize = 20;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let elements: Vec<char> = "BCFHKNOPSV".chars().collect();
+        let mut result: String = (0..size.max(2)).map(|_| elements[rng.below(10)]).collect();
+        result.push_str("\n\n");
+
+        for &left in elements.iter() {
+            for &right in elements.iter() {
+                writeln!(result, "{}{} -> {}", left, right, elements[rng.below(10)]).unwrap();
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,226 @@
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+mod generate;
+
+use common::{capture, ParseError, Problem, Solution};
+use pest::{Parser, Span};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Parser)]
+#[grammar = "manual.pest"]
+struct ManualParser;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Pair {
+    left: char,
+    right: char,
+}
+
+#[derive(Debug, Clone)]
+struct Ruler {
+    rules: HashMap<Pair, char>, // Pair insertion rules
+}
+
+impl Ruler {
+    fn expand(&self, pair: &Pair) -> (Pair, Pair) {
+        let insertion_element = self.rules.get(pair).unwrap();
+
+        let pair_1 = Pair {
+            left: pair.left,
+            right: *insertion_element,
+        };
+        let pair_2 = Pair {
+            left: *insertion_element,
+            right: pair.right,
+        };
+
+        (pair_1, pair_2)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    ruler: Ruler,
+    leftmost_element: char,
+    rightmost_element: char,
+    pair_counts: HashMap<Pair, u64>,
+}
+
+impl State {
+    fn new(input: &str) -> Result<State, ParseError> {
+        let mut polymer_template: Vec<char> = Vec::new();
+        let mut template_span = None;
+        let mut rules = HashMap::new();
+        let mut rule_spans = HashMap::new();
+
+        let pairs = ManualParser::parse(Rule::main, input)?;
+
+        for pair in pairs {
+            let rule = pair.as_rule();
+
+            match rule {
+                Rule::polymer_template => {
+                    template_span = Some(pair.as_span());
+                    polymer_template = capture(pair)?;
+                }
+                Rule::pair_insertion_rule => {
+                    let span = pair.as_span();
+                    let (left, right, element) = capture(pair)?;
+                    rules.insert(Pair { left, right }, element);
+                    rule_spans.insert(Pair { left, right }, span);
+                }
+                Rule::EOI => {}
+                _ => {
+                    let message = format!("unexpected {:?}", rule);
+                    return Err(Problem::at_span(pair.as_span(), message).into());
+                }
+            }
+        }
+
+        let ruler = Ruler { rules };
+
+        // Every pair which the template has, or which insertions make,
+        // needs a rule to expand it by, searching out from the template
+        let mut seen = HashSet::new();
+        let mut queue: Vec<(Pair, Option<Span<'_>>)> = polymer_template
+            .windows(2)
+            .map(|pair| {
+                (
+                    Pair {
+                        left: pair[0],
+                        right: pair[1],
+                    },
+                    None,
+                )
+            })
+            .collect();
+        while let Some((pair, made_by)) = queue.pop() {
+            if !seen.insert(pair.clone()) {
+                continue;
+            }
+            if !ruler.rules.contains_key(&pair) {
+                let (span, maker) = match made_by {
+                    Some(span) => (span, "this rule makes"),
+                    None => (
+                        template_span.expect("the grammar requires a template"),
+                        "the template has",
+                    ),
+                };
+                let message = format!(
+                    "{} the pair {}{}, which has no rule",
+                    maker, pair.left, pair.right
+                );
+                return Err(Problem::at_span(span, message).into());
+            }
+            let (pair_1, pair_2) = ruler.expand(&pair);
+            let span = rule_spans.get(&pair).copied();
+            queue.push((pair_1, span));
+            queue.push((pair_2, span));
+        }
+
+        let leftmost_element = polymer_template[0];
+        let rightmost_element = polymer_template[polymer_template.len() - 1];
+
+        let pairs: Vec<Pair> = polymer_template
+            .iter()
+            .cloned()
+            .zip(polymer_template[1..].iter().cloned())
+            .map(|(l, r)| Pair { left: l, right: r })
+            .collect();
+
+        let mut pair_counts: HashMap<Pair, u64> = HashMap::new();
+
+        for pair in pairs {
+            let o = pair_counts.entry(pair).or_insert(0);
+            *o += 1;
+        }
+
+        Ok(State {
+            ruler,
+            leftmost_element,
+            rightmost_element,
+            pair_counts,
+        })
+    }
+
+    fn step(&mut self) {
+        let mut new_pair_counts: HashMap<Pair, u64> = HashMap::new();
+
+        for (pair, count) in self.pair_counts.iter() {
+            let (new_pair_1, new_pair_2) = self.ruler.expand(pair);
+            let o = new_pair_counts.entry(new_pair_1).or_insert(0);
+            *o += count;
+            let o = new_pair_counts.entry(new_pair_2).or_insert(0);
+            *o += count;
+        }
+
+        self.pair_counts = new_pair_counts;
+    }
+
+    fn element_counts(&self) -> HashMap<char, u64> {
+        let mut element_counts = HashMap::new();
+
+        element_counts.insert(self.leftmost_element, 1);
+        element_counts.insert(self.rightmost_element, 1);
+
+        for (pair, count) in self.pair_counts.iter() {
+            let o = element_counts.entry(pair.left).or_insert(0);
+            *o += count;
+            let o = element_counts.entry(pair.right).or_insert(0);
+            *o += count;
+        }
+
+        for (_, count) in element_counts.iter_mut() {
+            *count /= 2;
+        }
+
+        element_counts
+    }
+
+    fn most_minus_least(&self) -> u64 {
+        let ec = self.element_counts();
+        let counts: Vec<u64> = ec.values().cloned().collect();
+        let &max = counts.iter().max().unwrap();
+        let &min = counts.iter().min().unwrap();
+
+        max - min
+    }
+}
+
+#[derive(Debug)]
+pub struct Day14 {
+    state: State,
+}
+
+impl Solution for Day14 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Day14, ParseError> {
+        let state = State::new(input)?;
+
+        Ok(Day14 { state })
+    }
+
+    fn part_1(&self) -> u64 {
+        let mut state = self.state.clone();
+
+        for _ in 1..=10 {
+            state.step();
+        }
+
+        state.most_minus_least()
+    }
+
+    fn part_2(&self) -> u64 {
+        let mut state = self.state.clone();
+
+        for _ in 1..=40 {
+            state.step();
+        }
+
+        state.most_minus_least()
+    }
+}
@@ -0,0 +1,13 @@
+use crate::Day15;
+use common::{Generate, Rng};
+
+impl Generate for Day15 {
+    // Width and height of the cavern
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+
+        rng.char_map(size, size, |rng| char::from(b'1' + rng.below(9) as u8))
+    }
+}
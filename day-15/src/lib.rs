@@ -0,0 +1,155 @@
+mod generate;
+
+use common::{dijkstra, Grid, Neighborhood, ParseError, Search, Solution};
+
+#[derive(Debug, Clone)]
+struct State {
+    positions: Grid<u32>,
+}
+
+impl State {
+    fn new(input: &str) -> Result<State, ParseError> {
+        let positions = Grid::parse(input, "a digit from 1 to 9", |ch| {
+            ch.to_digit(10).filter(|&digit| digit > 0)
+        })?;
+
+        Ok(State { positions })
+    }
+
+    fn expand_x25(&mut self) {
+        let width = self.positions.width();
+        let height = self.positions.height();
+        let mut new_positions = Grid::new(width * 5, height * 5, 0);
+
+        // Each tile is a copy of the original area, with risk levels raised
+        // by its distance from the top left tile, wrapping from 9 to 1
+        for (x, y) in new_positions.positions() {
+            let tile_distance = (x / width + y / height) as u32;
+            let value = self.positions[(x % width, y % height)] + tile_distance;
+            new_positions[(x, y)] = (value - 1) % 9 + 1;
+        }
+
+        self.positions = new_positions;
+    }
+
+    fn calculate_lowest_total_risk(&self) -> u32 {
+        // Each step costs the risk level of the position it enters, so
+        // the risk level of position (0, 0) is never counted
+        if let Some(path) = dijkstra(self, (0, 0)) {
+            path.cost
+        } else {
+            panic!("Lowest total risk not determined");
+        }
+    }
+}
+
+impl Search for State {
+    type State = (usize, usize);
+
+    fn successors(&self, &position: &(usize, usize)) -> Vec<((usize, usize), u32)> {
+        self.positions
+            .neighbors(position, Neighborhood::Four)
+            .map(|neighbor| (neighbor, self.positions[neighbor]))
+            .collect()
+    }
+
+    fn is_goal(&self, &position: &(usize, usize)) -> bool {
+        position == (self.positions.width() - 1, self.positions.height() - 1)
+    }
+}
+
+#[derive(Debug)]
+pub struct Day15 {
+    state: State,
+}
+
+impl Solution for Day15 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Day15, ParseError> {
+        let state = State::new(input)?;
+
+        Ok(Day15 { state })
+    }
+
+    fn part_1(&self) -> u32 {
+        self.state.calculate_lowest_total_risk()
+    }
+
+    fn part_2(&self) -> u32 {
+        let mut state = self.state.clone();
+        state.expand_x25();
+
+        state.calculate_lowest_total_risk()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Generate, Rng};
+    use proptest::prelude::*;
+
+    // Lower the risk to reach each position from its neighbors' until
+    // nothing changes, as in Bellman-Ford
+    fn brute_force(input: &str, tiles: usize) -> u32 {
+        let tile: Vec<Vec<u32>> = input
+            .lines()
+            .map(|line| line.chars().map(|ch| ch.to_digit(10).unwrap()).collect())
+            .collect();
+        let size = tile.len() * tiles;
+
+        // Each tile raises the risks by one more than the tile before it
+        let risks: Vec<Vec<u32>> = (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| {
+                        let raised = tile[y % tile.len()][x % tile.len()]
+                            + (x / tile.len() + y / tile.len()) as u32;
+                        (raised - 1) % 9 + 1
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut totals = vec![vec![u32::MAX; size]; size];
+        totals[0][0] = 0;
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for y in 0..size {
+                for x in 0..size {
+                    let neighbors = [
+                        (x.wrapping_sub(1), y),
+                        (x + 1, y),
+                        (x, y.wrapping_sub(1)),
+                        (x, y + 1),
+                    ];
+                    for (nx, ny) in neighbors {
+                        if nx >= size || ny >= size || totals[ny][nx] == u32::MAX {
+                            continue;
+                        }
+                        if totals[ny][nx] + risks[y][x] < totals[y][x] {
+                            totals[y][x] = totals[ny][nx] + risks[y][x];
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        totals[size - 1][size - 1]
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(seed in any::<u64>(), size in 1..20usize) {
+            let input = Day15::generate(&mut Rng::new(seed), size);
+            let day = Day15::parse(&input).unwrap();
+
+            prop_assert_eq!(day.part_1(), brute_force(&input, 1));
+            prop_assert_eq!(day.part_2(), brute_force(&input, 5));
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::Day16;
+use common::{Generate, Rng};
+
+fn push(bits: &mut Vec<bool>, value: u64, width: usize) {
+    for j in (0..width).rev() {
+        bits.push(value & 1 << j != 0);
+    }
+}
+
+fn literal(rng: &mut Rng, bits: &mut Vec<bool>) {
+    push(bits, rng.range(0, 7) as u64, 3);
+    push(bits, 4, 3);
+    let value = rng.range(0, 1000) as u64;
+    let groups = (64 - value.leading_zeros() as usize).max(1).div_ceil(4);
+    for group in (0..groups).rev() {
+        push(bits, u64::from(group > 0), 1);
+        push(bits, value >> (group * 4) & 0xf, 4);
+    }
+}
+
+fn packet(rng: &mut Rng, bits: &mut Vec<bool>, depth: usize) {
+    if depth == 0 {
+        return literal(rng, bits);
+    }
+
+    let type_id = [0, 1, 2, 3, 5, 6, 7][rng.below(7)];
+    let count = match type_id {
+        5..=7 => 2,
+        1 => rng.range(1, 3) as usize,
+        _ => rng.range(1, 4) as usize,
+    };
+    let mut children = Vec::new();
+    for _ in 0..count {
+        if type_id == 1 || rng.chance(30) {
+            literal(rng, &mut children);
+        } else {
+            packet(rng, &mut children, depth - 1);
+        }
+    }
+
+    push(bits, rng.range(0, 7) as u64, 3);
+    push(bits, type_id, 3);
+    if rng.chance(50) {
+        push(bits, 0, 1);
+        push(bits, children.len() as u64, 15);
+    } else {
+        push(bits, 1, 1);
+        push(bits, count as u64, 11);
+    }
+    bits.extend(children);
+}
+
+// Packets are built as a tree of random operators over small literals,
+// with products only over literals so that no value overflows, all inside
+// one outermost sum
+impl Generate for Day16 {
+    // Packets inside the outermost sum, at most 2047
+    const SIZE: usize = 60;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.min(2047);
+        let mut bits = Vec::new();
+        push(&mut bits, 0, 3);
+        push(&mut bits, 0, 3);
+        push(&mut bits, 1, 1);
+        push(&mut bits, size as u64, 11);
+        for _ in 0..size {
+            packet(rng, &mut bits, 4);
+        }
+        while bits.len() % 4 != 0 {
+            bits.push(false);
+        }
+
+        let mut result: String = bits
+            .chunks(4)
+            .map(|nibble| {
+                let value = nibble.iter().fold(0, |acc, &bit| acc * 2 + u32::from(bit));
+                std::char::from_digit(value, 16)
+                    .unwrap()
+                    .to_ascii_uppercase()
+            })
+            .collect();
+        result.push('\n');
+
+        result
+    }
+}
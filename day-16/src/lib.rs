@@ -0,0 +1,240 @@
+mod generate;
+
+use common::{ParseError, Problem, Solution};
+
+fn hexchar_to_bools(ch: char) -> Option<Vec<bool>> {
+    match ch {
+        '0' => Some(vec![false, false, false, false]),
+        '1' => Some(vec![false, false, false, true]),
+        '2' => Some(vec![false, false, true, false]),
+        '3' => Some(vec![false, false, true, true]),
+        '4' => Some(vec![false, true, false, false]),
+        '5' => Some(vec![false, true, false, true]),
+        '6' => Some(vec![false, true, true, false]),
+        '7' => Some(vec![false, true, true, true]),
+        '8' => Some(vec![true, false, false, false]),
+        '9' => Some(vec![true, false, false, true]),
+        'A' => Some(vec![true, false, true, false]),
+        'B' => Some(vec![true, false, true, true]),
+        'C' => Some(vec![true, true, false, false]),
+        'D' => Some(vec![true, true, false, true]),
+        'E' => Some(vec![true, true, true, false]),
+        'F' => Some(vec![true, true, true, true]),
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+struct Packet {
+    version: u64,
+    // Empty for a literal value
+    packets: Vec<Packet>,
+    // Worked out as the packet is decoded, so that overflow is found then
+    value: u64,
+}
+
+// Deeper packets are refused rather than decoded, so that decoding can't
+// run out of stack
+const MAX_DEPTH: usize = 256;
+
+impl Packet {
+    fn version_sum(&self) -> u64 {
+        self.version + self.packets.iter().map(Packet::version_sum).sum::<u64>()
+    }
+}
+
+// The value of an operator packet, or `None` if it is over 64 bits. The
+// decoder has checked that minimums and maximums have at least one
+// sub-packet, and comparisons exactly two
+fn operate(type_id: u64, packets: &[Packet]) -> Option<u64> {
+    let values: Vec<u64> = packets.iter().map(|packet| packet.value).collect();
+
+    Some(match type_id {
+        0 => values
+            .iter()
+            .try_fold(0u64, |sum, &value| sum.checked_add(value))?,
+        1 => values
+            .iter()
+            .try_fold(1u64, |product, &value| product.checked_mul(value))?,
+        2 => *values.iter().min().unwrap(),
+        3 => *values.iter().max().unwrap(),
+        5 => u64::from(values[0] > values[1]),
+        6 => u64::from(values[0] < values[1]),
+        7 => u64::from(values[0] == values[1]),
+        _ => panic!("Unknown packet type id {}", type_id),
+    })
+}
+
+// Reads packets from the bits of the transmission, failing with the index
+// of the bit where a packet goes wrong
+struct Decoder {
+    data: Vec<bool>,
+    cursor: usize,
+    // How many packets enclose the one being decoded
+    depth: usize,
+}
+
+impl Decoder {
+    fn take(&mut self, width: usize) -> Result<u64, (usize, String)> {
+        let Some(bits) = self.data.get(self.cursor..self.cursor + width) else {
+            let message = String::from("the transmission ends in the middle of a packet");
+            return Err((self.data.len(), message));
+        };
+        self.cursor += width;
+
+        Ok(bits
+            .iter()
+            .fold(0, |value, &bit| value * 2 + u64::from(bit)))
+    }
+
+    fn packet(&mut self) -> Result<Packet, (usize, String)> {
+        let start = self.cursor;
+        let version = self.take(3)?;
+        let type_id = self.take(3)?;
+
+        let (packets, value) = if type_id == 4 {
+            (Vec::new(), self.literal_value()?)
+        } else {
+            let packets = self.sub_packets()?;
+            let expected = match type_id {
+                2 | 3 if packets.is_empty() => Some("at least one sub-packet"),
+                5..=7 if packets.len() != 2 => Some("exactly two sub-packets"),
+                _ => None,
+            };
+            if let Some(expected) = expected {
+                let message = format!(
+                    "expected {} in a packet of type {}, found {}",
+                    expected,
+                    type_id,
+                    packets.len()
+                );
+                return Err((start, message));
+            }
+            let value = operate(type_id, &packets).ok_or_else(|| {
+                let message = format!(
+                    "the value of this packet of type {} is over 64 bits",
+                    type_id
+                );
+                (start, message)
+            })?;
+            (packets, value)
+        };
+
+        Ok(Packet {
+            version,
+            packets,
+            value,
+        })
+    }
+
+    fn literal_value(&mut self) -> Result<u64, (usize, String)> {
+        let start = self.cursor;
+        let mut value: u64 = 0;
+
+        loop {
+            let group_flag = self.take(1)?;
+            if value >> 60 != 0 {
+                return Err((start, String::from("the literal value is over 64 bits")));
+            }
+            value = value << 4 | self.take(4)?;
+            if group_flag == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn sub_packets(&mut self) -> Result<Vec<Packet>, (usize, String)> {
+        if self.depth == MAX_DEPTH {
+            let message = format!("packets are nested more than {} deep", MAX_DEPTH);
+            return Err((self.cursor, message));
+        }
+        self.depth += 1;
+        let packets = self.sub_packets_within()?;
+        self.depth -= 1;
+
+        Ok(packets)
+    }
+
+    fn sub_packets_within(&mut self) -> Result<Vec<Packet>, (usize, String)> {
+        let mut packets = Vec::new();
+
+        if self.take(1)? == 0 {
+            let total_length_in_bits = self.take(15)? as usize;
+            let end_of_packet_index = self.cursor + total_length_in_bits;
+
+            while self.cursor < end_of_packet_index {
+                packets.push(self.packet()?);
+            }
+            if self.cursor > end_of_packet_index {
+                let message = format!(
+                    "the sub-packets are longer than their total length of {} bits",
+                    total_length_in_bits
+                );
+                return Err((end_of_packet_index, message));
+            }
+        } else {
+            let subpacket_count = self.take(11)?;
+
+            for _ in 0..subpacket_count {
+                packets.push(self.packet()?);
+            }
+        }
+
+        Ok(packets)
+    }
+}
+
+#[derive(Debug)]
+pub struct Day16 {
+    packet: Packet,
+}
+
+impl Solution for Day16 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Day16, ParseError> {
+        let line = input.trim_end();
+        let indent = line.len() - line.trim_start().len();
+
+        let mut data = Vec::new();
+        let mut problems = Vec::new();
+        for (x, ch) in line.trim_start().chars().enumerate() {
+            match hexchar_to_bools(ch) {
+                Some(bools) => data.extend(bools),
+                None => problems.push(Problem::new(
+                    1,
+                    indent + x + 1,
+                    line,
+                    &ch.to_string(),
+                    format!("expected a hexadecimal digit, found {:?}", ch),
+                )),
+            }
+        }
+        if !problems.is_empty() {
+            return Err(ParseError::new(problems));
+        }
+
+        // Point at the hexadecimal digit holding the bit where decoding went
+        // wrong, or just past the last digit
+        let mut decoder = Decoder {
+            data,
+            cursor: 0,
+            depth: 0,
+        };
+        let packet = decoder.packet().map_err(|(bit, message)| {
+            let x = indent + bit / 4;
+            Problem::new(1, x + 1, line, line.get(x..x + 1).unwrap_or(""), message)
+        })?;
+
+        Ok(Day16 { packet })
+    }
+
+    fn part_1(&self) -> u64 {
+        self.packet.version_sum()
+    }
+
+    fn part_2(&self) -> u64 {
+        self.packet.value
+    }
+}
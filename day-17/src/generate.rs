@@ -0,0 +1,17 @@
+use crate::Day17;
+use common::{Generate, Rng};
+
+impl Generate for Day17 {
+    // Distance to the target area
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(10) as i64;
+        let x1 = rng.range(size, 2 * size);
+        let x2 = x1 + rng.range(size * 3 / 20, size * 7 / 20);
+        let y1 = rng.range(-size * 6 / 5, -size * 9 / 10);
+        let y2 = y1 + rng.range(size / 5, size * 3 / 10);
+
+        format!("target area: x={}..{}, y={}..{}\n", x1, x2, y1, y2)
+    }
+}
@@ -0,0 +1,236 @@
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+mod generate;
+
+use common::{capture, ParseError, Problem};
+use pest::{Parser, Span};
+use std::cmp::Ordering;
+
+#[derive(Parser)]
+#[grammar = "../../common/src/numbers.pest"]
+#[grammar = "target.pest"]
+struct TargetParser;
+
+// How far away a target can be, so that no probe fired at it goes past
+// what an i32 can hold
+const MAX_DISTANCE: i32 = 1 << 20;
+
+#[derive(Debug, Clone)]
+struct Solution {
+    velocity_x: i32,
+    velocity_y: i32,
+    steps: Vec<(i32, i32)>,
+    max_y_option: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    target_x_min: i32,
+    target_x_max: i32,
+    target_y_min: i32,
+    target_y_max: i32,
+    solutions: Vec<Solution>,
+}
+
+impl State {
+    fn new(input: &str) -> Result<State, ParseError> {
+        let pair = TargetParser::parse(Rule::main, input)?.next().unwrap();
+        let spans: Vec<Span<'_>> = pair
+            .clone()
+            .into_inner()
+            .map(|value| value.as_span())
+            .collect();
+
+        let (target_x_min, target_x_max, target_y_min, target_y_max): (i32, i32, i32, i32) =
+            capture(pair)?;
+
+        // The probes are fired forward and down, and only ever as far as
+        // the target, so the target has to be ahead and below
+        let checks = [
+            (
+                0,
+                target_x_min > 0,
+                "expected the target to be ahead, at x above 0",
+            ),
+            (
+                3,
+                target_y_max < 0,
+                "expected the target to be below, at y under 0",
+            ),
+            (
+                1,
+                target_x_min <= target_x_max,
+                "expected the range of x to run from low to high",
+            ),
+            (
+                3,
+                target_y_min <= target_y_max,
+                "expected the range of y to run from low to high",
+            ),
+        ];
+        for (j, ok, message) in checks {
+            if !ok {
+                return Err(Problem::at_span(spans[j], message).into());
+            }
+        }
+        for (span, value) in
+            spans
+                .iter()
+                .zip([target_x_min, target_x_max, target_y_min, target_y_max])
+        {
+            if value.unsigned_abs() > MAX_DISTANCE as u32 {
+                let message = format!("expected the target to be at most {} away", MAX_DISTANCE);
+                return Err(Problem::at_span(*span, message).into());
+            }
+        }
+
+        let solutions = Vec::new();
+
+        Ok(State {
+            target_x_min,
+            target_x_max,
+            target_y_min,
+            target_y_max,
+            solutions,
+        })
+    }
+
+    fn fire_probe(&mut self, velocity_x: i32, velocity_y: i32) {
+        let mut adjusted_velocity_x = velocity_x;
+        let mut adjusted_velocity_y = velocity_y;
+        let mut steps = Vec::new();
+        let mut max_y_option = None;
+        let mut max_y = self.target_y_min;
+
+        let mut x = 0;
+        let mut y = 0;
+        steps.push((x, y));
+
+        while x <= self.target_x_max && y >= self.target_y_min {
+            x += adjusted_velocity_x;
+            y += adjusted_velocity_y;
+            match adjusted_velocity_x.cmp(&0) {
+                Ordering::Less => adjusted_velocity_x += 1,
+                Ordering::Equal => (),
+                Ordering::Greater => adjusted_velocity_x -= 1,
+            }
+            adjusted_velocity_y -= 1;
+
+            steps.push((x, y));
+
+            if y > max_y {
+                max_y = y;
+            }
+
+            if x >= self.target_x_min
+                && x <= self.target_x_max
+                && y >= self.target_y_min
+                && y <= self.target_y_max
+            {
+                max_y_option = Some(max_y);
+            }
+        }
+
+        self.solutions.push(Solution {
+            velocity_x,
+            velocity_y,
+            steps,
+            max_y_option,
+        });
+    }
+
+    // Fire the probe at every velocity which could reach the target. Any
+    // faster forward, or faster down, overshoots it on the first step. Fired
+    // up at `v`, the probe comes back down past 0 at `v + 1`, so any faster
+    // up overshoots it too.
+    fn fire_probes(&mut self) {
+        for velocity_x in 0..=self.target_x_max {
+            for velocity_y in self.target_y_min..-self.target_y_min {
+                self.fire_probe(velocity_x, velocity_y);
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    fn display_firing(&self, solution: Solution) {
+        println!("Velocity: {}, {}", solution.velocity_x, solution.velocity_y);
+        if let Some(max_y) = solution.max_y_option {
+            println!("Success, maximum y is {}", max_y);
+        } else {
+            println!("Failure");
+        }
+
+        // The steps start from (0, 0)
+        let steps_min_x = solution.steps.iter().map(|(x, _)| *x).fold(0, i32::min);
+        let steps_max_y = solution.steps.iter().map(|(_, y)| *y).fold(0, i32::max);
+
+        let mut y = steps_max_y;
+        loop {
+            for x in steps_min_x..=self.target_x_max {
+                if x == 0 && y == 0 {
+                    print!("S");
+                    continue;
+                }
+                if solution.steps.contains(&(x, y)) {
+                    print!("#");
+                    continue;
+                }
+                if x >= self.target_x_min && y <= self.target_y_max {
+                    print!("T");
+                    continue;
+                }
+                print!(".");
+            }
+            println!();
+            y -= 1;
+            if y < self.target_y_min {
+                break;
+            }
+        }
+        println!();
+    }
+}
+
+#[derive(Debug)]
+pub struct Day17 {
+    state: State,
+}
+
+impl common::Solution for Day17 {
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Day17, ParseError> {
+        let state = State::new(input)?;
+
+        Ok(Day17 { state })
+    }
+
+    fn part_1(&self) -> i32 {
+        let mut state = self.state.clone();
+        state.fire_probes();
+
+        // The highest of any solution. Fired straight at the target's top
+        // left corner, the probe always hits it, so there is one
+        state
+            .solutions
+            .iter()
+            .filter_map(|solution| solution.max_y_option)
+            .max()
+            .unwrap_or(self.state.target_y_max)
+    }
+
+    fn part_2(&self) -> usize {
+        let mut state = self.state.clone();
+        state.fire_probes();
+
+        // Number of successful solutions
+        state
+            .solutions
+            .iter()
+            .filter(|solution| solution.max_y_option.is_some())
+            .count()
+    }
+}
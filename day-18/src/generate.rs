@@ -0,0 +1,33 @@
+use crate::Day18;
+use common::{Generate, Rng};
+
+impl Generate for Day18 {
+    // Snailfish numbers
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        fn pair(rng: &mut Rng, depth: usize, result: &mut String) {
+            result.push('[');
+            for j in 0..2 {
+                if j == 1 {
+                    result.push(',');
+                }
+                if depth < 4 && rng.chance(60) {
+                    pair(rng, depth + 1, result);
+                } else {
+                    result.push(char::from(b'0' + rng.below(10) as u8));
+                }
+            }
+            result.push(']');
+        }
+
+        let size = size.max(2);
+        let mut result = String::new();
+        for _ in 0..size {
+            pair(rng, 1, &mut result);
+            result.push('\n');
+        }
+
+        result
+    }
+}
@@ -0,0 +1,106 @@
+use crate::Day19;
+use common::{Generate, Rng};
+use std::fmt::Write;
+
+// The 24 rotation matrices, those of the signed permutations with a
+// determinant of 1
+fn rotations() -> Vec<[[i64; 3]; 3]> {
+    let mut rotations = Vec::new();
+    for permutation in [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ] {
+        for signs in 0..8 {
+            let mut matrix = [[0; 3]; 3];
+            for (row, &column) in permutation.iter().enumerate() {
+                matrix[row][column] = if signs & 1 << row != 0 { -1 } else { 1 };
+            }
+            let determinant = matrix[0][0]
+                * (matrix[1][1] * matrix[2][2] - matrix[1][2] * matrix[2][1])
+                - matrix[0][1] * (matrix[1][0] * matrix[2][2] - matrix[1][2] * matrix[2][0])
+                + matrix[0][2] * (matrix[1][0] * matrix[2][1] - matrix[1][1] * matrix[2][0]);
+            if determinant == 1 {
+                rotations.push(matrix);
+            }
+        }
+    }
+    rotations
+}
+
+// Scanners are placed in a chain, each sharing at least 12 beacons with
+// the next, and report the beacons within 1000 on each axis in one of the
+// 24 orientations
+impl Generate for Day19 {
+    // Scanners
+    const SIZE: usize = 30;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let rotations = rotations();
+        let scanners: Vec<[i64; 3]> = (0..size.max(1) as i64)
+            .map(|j| {
+                [
+                    j * 1000 + rng.range(-80, 80),
+                    rng.range(-150, 150),
+                    rng.range(-150, 150),
+                ]
+            })
+            .collect();
+
+        let mut beacons = Vec::new();
+        for pair in scanners.windows(2) {
+            for _ in 0..12 {
+                beacons.push([
+                    rng.range(pair[1][0] - 1000, pair[0][0] + 1000),
+                    rng.range(
+                        pair[1][1].max(pair[0][1]) - 1000,
+                        pair[1][1].min(pair[0][1]) + 1000,
+                    ),
+                    rng.range(
+                        pair[1][2].max(pair[0][2]) - 1000,
+                        pair[1][2].min(pair[0][2]) + 1000,
+                    ),
+                ]);
+            }
+        }
+        for scanner in scanners.iter() {
+            for _ in 0..12 {
+                beacons.push([
+                    scanner[0] + rng.range(-400, 400),
+                    scanner[1] + rng.range(-1000, 1000),
+                    scanner[2] + rng.range(-1000, 1000),
+                ]);
+            }
+        }
+
+        let mut result = String::new();
+        for (id, scanner) in scanners.iter().enumerate() {
+            if id > 0 {
+                result.push('\n');
+            }
+            writeln!(result, "--- scanner {} ---", id).unwrap();
+            let rotation = if id == 0 {
+                rotations[0]
+            } else {
+                rotations[rng.below(rotations.len())]
+            };
+            for beacon in beacons.iter() {
+                let relative: Vec<i64> = (0..3).map(|axis| beacon[axis] - scanner[axis]).collect();
+                if relative.iter().any(|value| value.abs() > 1000) {
+                    continue;
+                }
+                let rotated: Vec<String> = rotation
+                    .iter()
+                    .map(|row| (0..3).map(|axis| row[axis] * relative[axis]).sum::<i64>())
+                    .map(|value| value.to_string())
+                    .collect();
+                writeln!(result, "{}", rotated.join(",")).unwrap();
+            }
+        }
+
+        result
+    }
+}
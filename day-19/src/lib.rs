@@ -0,0 +1,451 @@
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+mod generate;
+
+use common::{capture, NoAnswer, ParseError, Problem, Rotation, Solution, Vec3};
+use pest::{Parser, Span};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+#[derive(Parser)]
+#[grammar = "../../common/src/numbers.pest"]
+#[grammar = "scanner.pest"]
+struct ScannerParser;
+
+// Scanners only see beacons this far away along each axis, which also
+// keeps the offsets between beacons well within an i32
+const MAX_DISTANCE: i32 = 1000;
+
+// The lengths of an offset along each axis, smallest first, which are the
+// same however the scanner which saw it is turned
+fn fingerprint(offset: Vec3<i32>) -> [i32; 3] {
+    let Vec3 { x, y, z } = offset.abs();
+    let mut lengths = [x, y, z];
+    lengths.sort_unstable();
+
+    lengths
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Pair {
+    beacon_1: Vec3<i32>,
+    beacon_2: Vec3<i32>,
+}
+
+impl fmt::Display for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut result = String::new();
+        result.push_str(&format!("({}) and ({})", self.beacon_1, self.beacon_2));
+
+        write!(f, "{}", result)
+    }
+}
+
+impl Pair {
+    fn new(beacon_1: Vec3<i32>, beacon_2: Vec3<i32>) -> Pair {
+        Pair { beacon_1, beacon_2 }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Scanner {
+    id: u32,
+    offset_option: Option<Vec3<i32>>,
+    beacons: HashSet<Vec3<i32>>,
+}
+
+impl fmt::Display for Scanner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut result = String::new();
+
+        result.push_str(&format!("Scanner {}", self.id));
+        result.push_str(&format!("\nOffset {:?}", self.offset_option));
+
+        result.push_str("\nBeacons");
+        for beacon in self.beacons.iter() {
+            result.push_str(&format!("\n({})", beacon));
+        }
+        result.push('\n');
+
+        write!(f, "{}", result)
+    }
+}
+
+impl Scanner {
+    fn new(id: u32, beacons: HashSet<Vec3<i32>>) -> Scanner {
+        let offset_option = None;
+        Scanner {
+            id,
+            offset_option,
+            beacons,
+        }
+    }
+
+    fn pair_offsets(&self) -> HashMap<Vec3<i32>, Pair> {
+        let mut pair_offsets = HashMap::new();
+
+        for beacon_1 in self.beacons.iter() {
+            for beacon_2 in self.beacons.iter() {
+                if beacon_1 != beacon_2 {
+                    pair_offsets.insert(*beacon_1 - *beacon_2, Pair::new(*beacon_1, *beacon_2));
+                }
+            }
+        }
+
+        pair_offsets
+    }
+
+    fn reorient(&self, rotation: Rotation) -> Scanner {
+        let mut beacons = HashSet::new();
+
+        for beacon in self.beacons.iter() {
+            beacons.insert(rotation.apply(*beacon));
+        }
+
+        Scanner::new(self.id, beacons)
+    }
+
+    fn reorient_other(&self, other: &Scanner) -> Option<Scanner> {
+        let own_pair_offsets = self.pair_offsets();
+        let other_pair_offsets = other.pair_offsets();
+
+        let other_pair_fingerprints: HashSet<[i32; 3]> = other_pair_offsets
+            .keys()
+            .map(|&offset| fingerprint(offset))
+            .collect();
+
+        let mut shared_own_beacons = HashSet::new();
+
+        for (offset, own_pair) in own_pair_offsets.iter() {
+            if other_pair_fingerprints.contains(&fingerprint(*offset)) {
+                shared_own_beacons.insert(own_pair.beacon_1);
+                shared_own_beacons.insert(own_pair.beacon_2);
+            }
+        }
+
+        if shared_own_beacons.len() < 12 {
+            return None;
+        }
+
+        for rotation in Rotation::all() {
+            let mut reoriented_other = other.reorient(rotation);
+            let reoriented_other_pair_offsets = reoriented_other.pair_offsets();
+
+            let mut other_scanner_offsets: HashMap<Vec3<i32>, u32> = HashMap::new();
+
+            for (other_offset, other_pair) in reoriented_other_pair_offsets.iter() {
+                if let Some(own_pair) = own_pair_offsets.get(other_offset) {
+                    let o_b1_b1 = own_pair.beacon_1 - other_pair.beacon_1;
+                    let o_b2_b2 = own_pair.beacon_2 - other_pair.beacon_2;
+                    let o_b1_b2 = own_pair.beacon_1 - other_pair.beacon_2;
+                    let o_b2_b1 = own_pair.beacon_2 - other_pair.beacon_1;
+
+                    if o_b1_b1 == o_b2_b2 {
+                        let o = other_scanner_offsets.entry(o_b1_b1).or_insert(0);
+                        *o += 1;
+                        continue;
+                    }
+                    if o_b1_b2 == o_b2_b1 {
+                        let o = other_scanner_offsets.entry(o_b1_b2).or_insert(0);
+                        *o += 1;
+                    }
+                }
+            }
+
+            let other_scanner_offset = if let Some((&offset, _)) = other_scanner_offsets
+                .iter()
+                .find(|(_, &count)| count >= 12 * 11)
+            {
+                offset
+            } else {
+                continue;
+            };
+
+            let mut translated_beacons = HashSet::new();
+            for beacon in reoriented_other.beacons.iter() {
+                translated_beacons.insert(*beacon + other_scanner_offset);
+            }
+            reoriented_other.beacons = translated_beacons;
+            reoriented_other.offset_option = Some(other_scanner_offset);
+
+            return Some(reoriented_other);
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    scanners: Vec<Scanner>,
+}
+
+impl State {
+    fn new(input: &str) -> Result<State, ParseError> {
+        let mut scanners = Vec::new();
+        let mut scanner_id_option: Option<u32> = None;
+        let mut beacons = HashSet::new();
+
+        let pairs = ScannerParser::parse(Rule::main, input)?;
+
+        for pair in pairs {
+            let rule = pair.as_rule();
+
+            match rule {
+                Rule::scanner_id => {
+                    if let Some(id) = scanner_id_option {
+                        scanners.push(Scanner::new(id, beacons));
+                        beacons = HashSet::new();
+                    }
+
+                    let scanner_id = capture(pair)?;
+                    scanner_id_option = Some(scanner_id);
+                }
+                Rule::beacon => {
+                    let spans: Vec<Span<'_>> = pair
+                        .clone()
+                        .into_inner()
+                        .map(|value| value.as_span())
+                        .collect();
+                    let (x, y, z): (i32, i32, i32) = capture(pair)?;
+                    for (span, value) in spans.iter().zip([x, y, z]) {
+                        if value.unsigned_abs() > MAX_DISTANCE as u32 {
+                            let message = format!(
+                                "expected a beacon at most {} from its scanner",
+                                MAX_DISTANCE
+                            );
+                            return Err(Problem::at_span(*span, message).into());
+                        }
+                    }
+                    beacons.insert(Vec3::new(x, y, z));
+                }
+                Rule::EOI => {}
+                _ => {
+                    panic!("Unknown rule {:?} with {:?}", rule, pair.as_str());
+                }
+            }
+        }
+
+        if let Some(id) = scanner_id_option {
+            scanners.push(Scanner::new(id, beacons));
+        }
+
+        Ok(State { scanners })
+    }
+
+    // The scanners placed relative to scanner 0, or which of them share
+    // too few beacons with the others to be placed
+    fn placed(&self) -> Result<State, NoAnswer> {
+        let mut state = self.clone();
+        if let Err(unplaced) = state.reorient_scanners() {
+            let ids: Vec<String> = unplaced
+                .iter()
+                .map(|&j| state.scanners[j].id.to_string())
+                .collect();
+            let reason = match ids.len() {
+                1 => format!("scanner {} shares", ids[0]),
+                _ => format!("scanners {} share", ids.join(", ")),
+            };
+            return Err(NoAnswer::new(format!(
+                "{} fewer than 12 beacons with the other scanners",
+                reason
+            )));
+        }
+
+        Ok(state)
+    }
+
+    // Place every scanner relative to scanner 0, returning the indexes of
+    // any which share too few beacons with the others to be placed
+    fn reorient_scanners(&mut self) -> Result<(), Vec<usize>> {
+        if self.scanners.is_empty() {
+            return Ok(());
+        }
+
+        self.scanners[0].offset_option = Some(Vec3::default());
+
+        // Each newly placed scanner is tried against every scanner not yet
+        // placed, so each pair is tried at most once
+        let mut newly_placed = VecDeque::from([0]);
+        while let Some(j) = newly_placed.pop_front() {
+            for k in 0..self.scanners.len() {
+                if self.scanners[k].offset_option.is_some() {
+                    continue;
+                }
+                if let Some(scanner) = self.scanners[j].reorient_other(&self.scanners[k]) {
+                    self.scanners[k] = scanner;
+                    newly_placed.push_back(k);
+                }
+            }
+        }
+
+        let unplaced: Vec<usize> = (0..self.scanners.len())
+            .filter(|&j| self.scanners[j].offset_option.is_none())
+            .collect();
+
+        if unplaced.is_empty() {
+            Ok(())
+        } else {
+            Err(unplaced)
+        }
+    }
+
+    fn beacon_count(&self) -> usize {
+        let mut all_beacons = HashSet::new();
+
+        for scanner in self.scanners.iter() {
+            for beacon in scanner.beacons.iter() {
+                all_beacons.insert(beacon);
+            }
+        }
+
+        all_beacons.len()
+    }
+
+    fn largest_manhattan_distance(&self) -> i32 {
+        let mut largest_manhattan_distance = 0;
+
+        for j in 0..self.scanners.len() {
+            for k in 0..self.scanners.len() {
+                let offset_j = self.scanners[j].offset_option.unwrap();
+                let offset_k = self.scanners[k].offset_option.unwrap();
+                let distance = (offset_j - offset_k).manhattan();
+                if distance > largest_manhattan_distance {
+                    largest_manhattan_distance = distance;
+                }
+            }
+        }
+
+        largest_manhattan_distance
+    }
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut result = String::new();
+
+        for scanner in self.scanners.iter() {
+            result.push_str(&format!("{}\n", scanner));
+        }
+
+        write!(f, "{}", result)
+    }
+}
+
+#[derive(Debug)]
+pub struct Day19 {
+    state: State,
+}
+
+impl Solution for Day19 {
+    type Part1 = Result<usize, NoAnswer>;
+    type Part2 = Result<i32, NoAnswer>;
+
+    fn parse(input: &str) -> Result<Day19, ParseError> {
+        let state = State::new(input)?;
+
+        Ok(Day19 { state })
+    }
+
+    fn part_1(&self) -> Result<usize, NoAnswer> {
+        Ok(self.state.placed()?.beacon_count())
+    }
+
+    fn part_2(&self) -> Result<i32, NoAnswer> {
+        Ok(self.state.placed()?.largest_manhattan_distance())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Generate, Rng};
+    use proptest::prelude::*;
+
+    // A scanner's position and its beacons, relative to scanner 0
+    type Placed = (Vec3<i32>, Vec<Vec3<i32>>);
+
+    // Try every turn of each scanner not yet placed against each one which
+    // is, counting the beacons which line up for every offset between two
+    // of their beacons
+    fn brute_force(input: &str) -> (usize, i32) {
+        let mut scanners: Vec<Vec<Vec3<i32>>> = Vec::new();
+        for line in input.lines() {
+            if line.starts_with("---") {
+                scanners.push(Vec::new());
+            } else if !line.is_empty() {
+                let numbers: Vec<i32> = line.split(',').map(|n| n.parse().unwrap()).collect();
+                let beacon = Vec3::new(numbers[0], numbers[1], numbers[2]);
+                scanners.last_mut().unwrap().push(beacon);
+            }
+        }
+
+        let mut placed: Vec<Option<Placed>> = vec![None; scanners.len()];
+        placed[0] = Some((Vec3::default(), scanners[0].clone()));
+        while placed.iter().any(|scanner| scanner.is_none()) {
+            for j in 0..scanners.len() {
+                for k in 0..scanners.len() {
+                    let (Some((_, known)), None) = (&placed[j], &placed[k]) else {
+                        continue;
+                    };
+                    for rotation in Rotation::all() {
+                        let turned: Vec<Vec3<i32>> = scanners[k]
+                            .iter()
+                            .map(|&beacon| rotation.apply(beacon))
+                            .collect();
+                        let mut counts: HashMap<Vec3<i32>, usize> = HashMap::new();
+                        for &a in known.iter() {
+                            for &b in turned.iter() {
+                                *counts.entry(a - b).or_insert(0) += 1;
+                            }
+                        }
+                        if let Some((&offset, _)) = counts.iter().find(|(_, &count)| count >= 12) {
+                            let beacons = turned.iter().map(|&beacon| beacon + offset).collect();
+                            placed[k] = Some((offset, beacons));
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        let placed: Vec<Placed> = placed.into_iter().flatten().collect();
+        let beacons: HashSet<Vec3<i32>> = placed
+            .iter()
+            .flat_map(|(_, beacons)| beacons.iter().copied())
+            .collect();
+        let distance = placed
+            .iter()
+            .flat_map(|(a, _)| placed.iter().map(move |(b, _)| (*a - *b).manhattan()))
+            .max()
+            .unwrap();
+
+        (beacons.len(), distance)
+    }
+
+    #[test]
+    fn scanners_which_never_overlap() {
+        let input = "--- scanner 0 ---\n1,2,3\n4,5,6\n\n--- scanner 1 ---\n7,8,9\n";
+        let solution = Day19::parse(input).unwrap();
+
+        assert_eq!(
+            solution.part_1(),
+            Err(NoAnswer::new(
+                "scanner 1 shares fewer than 12 beacons with the other scanners"
+            ))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(seed in any::<u64>(), size in 1..6usize) {
+            let input = Day19::generate(&mut Rng::new(seed), size);
+            let day = Day19::parse(&input).unwrap();
+            let (part_1, part_2) = brute_force(&input);
+
+            prop_assert_eq!((day.part_1(), day.part_2()), (Ok(part_1), Ok(part_2)));
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::Day20;
+use common::{Generate, Rng};
+
+// The algorithm lights every dark pixel of the infinite field and darkens
+// every lit one, as in personal puzzle inputs
+impl Generate for Day20 {
+    // Width and height of the image
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+
+        let mut result = String::from("#");
+        for _ in 1..511 {
+            result.push(if rng.chance(50) { '#' } else { '.' });
+        }
+        result.push_str(".\n\n");
+
+        result + &rng.char_map(size, size, |rng| if rng.chance(50) { '#' } else { '.' })
+    }
+}
@@ -0,0 +1,261 @@
+mod generate;
+
+use common::{parse_lines, Grid, ParseError, Problem, Recording, Simulation, Solution};
+use std::fmt;
+
+const PIXEL: &str = "'#' or '.'";
+
+fn pixel(ch: char) -> Option<bool> {
+    match ch {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_pixels(line: &str) -> Result<Vec<bool>, ParseError> {
+    let mut pixels = Vec::new();
+    let mut problems = Vec::new();
+
+    for (x, ch) in line.chars().enumerate() {
+        match pixel(ch) {
+            Some(value) => pixels.push(value),
+            None => problems.push(Problem::new(
+                1,
+                x + 1,
+                line,
+                &ch.to_string(),
+                format!("expected {}, found {:?}", PIXEL, ch),
+            )),
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(pixels)
+    } else {
+        Err(ParseError::new(problems))
+    }
+}
+
+// Image enhancement algorithm
+#[derive(Debug, Clone)]
+struct Iea {
+    pixels: Vec<bool>,
+}
+
+impl fmt::Display for Iea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut result = String::new();
+
+        for x in 0..self.pixels.len() {
+            result.push(if self.pixels[x] { '#' } else { '.' });
+        }
+        result.push('\n');
+
+        write!(f, "{}", result)
+    }
+}
+
+impl Iea {
+    fn new(pixels: Vec<bool>) -> Iea {
+        Iea { pixels }
+    }
+
+    fn apply(&self, number: usize) -> bool {
+        self.pixels[number]
+    }
+
+    // Account for the "fun" part of this puzzle
+    fn field_flips(&self) -> bool {
+        self.pixels[0]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Image {
+    pixel_rows: Grid<bool>,
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut result = String::new();
+
+        for row in self.pixel_rows.rows() {
+            for &pixel in row.iter() {
+                result.push(if pixel { '#' } else { '.' });
+            }
+            result.push('\n');
+        }
+
+        write!(f, "{}", result)
+    }
+}
+
+impl Image {
+    fn new(pixel_rows: Grid<bool>) -> Image {
+        Image { pixel_rows }
+    }
+
+    fn generate_output_image(&self, field: bool, iea: &Iea) -> Image {
+        // Every pixel beyond the image is `field`, so a border of two is
+        // enough to cover each output pixel's square of neighbors
+        let mut input_pixel_rows = Grid::new(
+            self.pixel_rows.width() + 4,
+            self.pixel_rows.height() + 4,
+            field,
+        );
+        for ((x, y), &pixel) in self.pixel_rows.iter() {
+            input_pixel_rows[(x + 2, y + 2)] = pixel;
+        }
+
+        let mut output_pixel_rows = Grid::new(
+            self.pixel_rows.width() + 2,
+            self.pixel_rows.height() + 2,
+            false,
+        );
+
+        for (x, y) in output_pixel_rows.positions() {
+            let (x, y) = (x + 1, y + 1);
+            let pixels = [
+                input_pixel_rows[(x - 1, y - 1)],
+                input_pixel_rows[(x, y - 1)],
+                input_pixel_rows[(x + 1, y - 1)],
+                input_pixel_rows[(x - 1, y)],
+                input_pixel_rows[(x, y)],
+                input_pixel_rows[(x + 1, y)],
+                input_pixel_rows[(x - 1, y + 1)],
+                input_pixel_rows[(x, y + 1)],
+                input_pixel_rows[(x + 1, y + 1)],
+            ];
+            let mut number = 0;
+            let mut digit = 1;
+            for &pixel in pixels.iter().rev() {
+                if pixel {
+                    number += digit;
+                }
+                digit *= 2;
+            }
+            output_pixel_rows[(x - 1, y - 1)] = iea.apply(number);
+        }
+
+        Image {
+            pixel_rows: output_pixel_rows,
+        }
+    }
+
+    fn pixel_count(&self) -> u32 {
+        self.pixel_rows.iter().filter(|&(_, &pixel)| pixel).count() as u32
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    iea: Iea,
+    image: Image,
+    field: bool,
+}
+
+impl State {
+    fn new(input: &str) -> Result<State, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+
+        // The algorithm may be wrapped over several lines, and is separated
+        // from the image by a blank line
+        let blank = lines
+            .iter()
+            .position(|line| line.is_empty())
+            .ok_or_else(|| Problem::at_end(input, "expected a blank line after the algorithm"))?;
+        let iea_pixels = parse_lines(&lines[..blank].join("\n"), parse_pixels)?.concat();
+
+        if iea_pixels.len() != 512 {
+            let line = lines[0];
+            return Err(Problem::new(
+                1,
+                1,
+                line,
+                line,
+                format!(
+                    "the algorithm has {} pixels, expected 512",
+                    iea_pixels.len()
+                ),
+            )
+            .into());
+        }
+
+        // A dark background would be lit by pixel 0 and then kept lit by
+        // pixel 511
+        if iea_pixels[0] && iea_pixels[511] {
+            let line = lines[0];
+            let message =
+                "the algorithm keeps the infinite background lit, so the count is infinite";
+            return Err(Problem::new(1, 1, line, line, message).into());
+        }
+
+        let image_rows = Grid::parse(&lines[blank + 1..].join("\n"), PIXEL, pixel)
+            .map_err(|e| e.below_lines(blank + 1))?;
+
+        let iea = Iea::new(iea_pixels);
+        let image = Image::new(image_rows);
+
+        let field = false;
+
+        Ok(State { iea, image, field })
+    }
+
+    fn apply_algorithm(&mut self) {
+        self.image = self.image.generate_output_image(self.field, &self.iea);
+        if self.iea.field_flips() {
+            self.field = !self.field;
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Day20 {
+    state: State,
+}
+
+impl Solution for Day20 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Day20, ParseError> {
+        let state = State::new(input)?;
+
+        Ok(Day20 { state })
+    }
+
+    fn part_1(&self) -> u32 {
+        let mut state = self.state.clone();
+        for _ in 0..2 {
+            state.apply_algorithm();
+        }
+
+        state.image.pixel_count()
+    }
+
+    fn part_2(&self) -> u32 {
+        let mut state = self.state.clone();
+        for _ in 0..50 {
+            state.apply_algorithm();
+        }
+
+        state.image.pixel_count()
+    }
+}
+
+// Every enhancement of the image, for part 2
+impl Simulation for Day20 {
+    fn record(&self) -> Recording {
+        let mut state = self.state.clone();
+        let mut recording = Recording::new(2);
+
+        recording.push(state.image.pixel_rows.map(|&pixel| pixel as u8));
+        for _ in 0..50 {
+            state.apply_algorithm();
+            recording.push(state.image.pixel_rows.map(|&pixel| pixel as u8));
+        }
+
+        recording
+    }
+}
@@ -0,0 +1,15 @@
+use crate::Day21;
+use common::{Generate, Rng};
+
+impl Generate for Day21 {
+    // Players, of which there are always two
+    const SIZE: usize = 2;
+
+    fn generate(rng: &mut Rng, _size: usize) -> String {
+        format!(
+            "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+            rng.range(1, 10),
+            rng.range(1, 10)
+        )
+    }
+}
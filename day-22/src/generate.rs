@@ -0,0 +1,47 @@
+use crate::Day22;
+use common::{Generate, Rng};
+use std::fmt::Write;
+
+impl Generate for Day22 {
+    // Reboot steps, of which one in 21 is in the initialization area
+    const SIZE: usize = 420;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+
+        let mut result = String::new();
+
+        for step in 0..size {
+            let (limit, extent) = if step < size / 21 {
+                (50, 50)
+            } else {
+                (100_000, 40_000)
+            };
+            let setting = if step == 0 || rng.chance(60) {
+                "on"
+            } else {
+                "off"
+            };
+            let mut ranges = Vec::new();
+            for _ in 0..3 {
+                let low = rng.range(-limit, limit - extent);
+                let high = low + rng.range(extent / 4, extent);
+                ranges.push((low, high));
+            }
+            writeln!(
+                result,
+                "{} x={}..{},y={}..{},z={}..{}",
+                setting,
+                ranges[0].0,
+                ranges[0].1,
+                ranges[1].0,
+                ranges[1].1,
+                ranges[2].0,
+                ranges[2].1
+            )
+            .unwrap();
+        }
+
+        result
+    }
+}
@@ -0,0 +1,262 @@
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+mod generate;
+
+use common::{
+    capture, parse_streamed, Capture, Cuboid, ParseError, Problem, Solution, Streaming, Vec3,
+};
+use pest::iterators::Pairs;
+use pest::{Parser, RuleType, Span};
+
+#[derive(Parser)]
+#[grammar = "../../common/src/numbers.pest"]
+#[grammar = "step.pest"]
+struct StepParser;
+
+// The initialization procedure only considers cubes in this region
+const INITIALIZATION_REGION: Cuboid<i64> =
+    Cuboid::new(Vec3::new(-50, -50, -50), Vec3::new(50, 50, 50));
+
+// How far from the origin a cube can be, so that the volume of the whole
+// reactor fits in an i64
+const MAX_COORDINATE: i64 = 1 << 18;
+
+// The low and high coordinates of a cuboid along one axis
+#[derive(Debug)]
+struct Range {
+    low: i64,
+    high: i64,
+}
+
+impl<'i> Capture<'i> for Range {
+    fn capture<R: RuleType>(pairs: &mut Pairs<'i, R>) -> Result<Range, Problem> {
+        let spans: Vec<Span<'_>> = pairs.clone().take(2).map(|pair| pair.as_span()).collect();
+        let (low, high): (i64, i64) = Capture::capture(pairs)?;
+
+        for (span, value) in spans.iter().zip([low, high]) {
+            if value.abs() > MAX_COORDINATE {
+                let message = format!("expected a coordinate at most {} away", MAX_COORDINATE);
+                return Err(Problem::at_span(*span, message));
+            }
+        }
+        if low > high {
+            let span = spans[0].start_pos().span(&spans[1].end_pos());
+            return Err(Problem::at_span(
+                span,
+                "expected the range to run from low to high",
+            ));
+        }
+
+        Ok(Range { low, high })
+    }
+}
+
+#[derive(Debug)]
+struct Step {
+    set_on: bool,
+    cuboid: Cuboid<i64>,
+}
+
+impl<'i> Capture<'i> for Step {
+    fn capture<R: RuleType>(pairs: &mut Pairs<'i, R>) -> Result<Step, Problem> {
+        let (setting, x, y, z): (&str, Range, Range, Range) = Capture::capture(pairs)?;
+
+        Ok(Step {
+            set_on: setting == "on",
+            cuboid: Cuboid::new(
+                Vec3::new(x.low, y.low, z.low),
+                Vec3::new(x.high, y.high, z.high),
+            ),
+        })
+    }
+}
+
+// The cubes lit so far, as cuboids which don't overlap
+#[derive(Debug, Default)]
+struct Reactor {
+    cuboids: Vec<Cuboid<i64>>,
+}
+
+impl Reactor {
+    fn reboot_step(&mut self, step: &Step) {
+        // Keep what is lit outside the step's cuboid, and then light all of
+        // it if the step turns it on
+        self.cuboids = self
+            .cuboids
+            .iter()
+            .flat_map(|cuboid| cuboid.difference(&step.cuboid))
+            .collect();
+
+        if step.set_on {
+            self.cuboids.push(step.cuboid);
+        }
+    }
+
+    fn lit(&self) -> i64 {
+        self.cuboids.iter().map(|cuboid| cuboid.volume()).sum()
+    }
+}
+
+// Each step is carried out as it's read, on the initialization region and
+// on the whole reactor, so the steps themselves aren't kept
+#[derive(Debug, Default)]
+struct State {
+    initialization: Reactor,
+    full: Reactor,
+}
+
+impl State {
+    fn parse_line(&mut self, line: &str) -> Result<(), ParseError> {
+        let pairs = StepParser::parse(Rule::main, line)?;
+
+        for pair in pairs {
+            let rule = pair.as_rule();
+
+            match rule {
+                Rule::step => {
+                    let step: Step = capture(pair)?;
+                    if let Some(cuboid) = step.cuboid.intersection(&INITIALIZATION_REGION) {
+                        let set_on = step.set_on;
+                        self.initialization.reboot_step(&Step { set_on, cuboid });
+                    }
+                    self.full.reboot_step(&step);
+                }
+                Rule::EOI => {}
+                _ => {
+                    panic!("Unknown rule {:?} with {:?}", rule, pair.as_str());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Day22 {
+    state: State,
+}
+
+impl Solution for Day22 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day22, ParseError> {
+        parse_streamed(input)
+    }
+
+    fn part_1(&self) -> i64 {
+        self.state.initialization.lit()
+    }
+
+    fn part_2(&self) -> i64 {
+        self.state.full.lit()
+    }
+}
+
+impl Streaming for Day22 {
+    fn parse_line(&mut self, line: &str) -> Result<(), ParseError> {
+        self.state.parse_line(line)
+    }
+
+    fn finish(self, lines: usize) -> Result<Day22, ParseError> {
+        if lines == 0 {
+            return Err(Problem::after_lines(lines, "expected a reboot step").into());
+        }
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Generate, Rng};
+    use proptest::prelude::*;
+
+    // Split space at every edge of a step, and switch the blocks between
+    // the splits on and off one by one
+    fn count_lit(steps: &[(bool, [[i64; 2]; 3])]) -> i64 {
+        let splits: Vec<Vec<i64>> = (0..3)
+            .map(|axis| {
+                let mut splits: Vec<i64> = steps
+                    .iter()
+                    .flat_map(|(_, ranges)| [ranges[axis][0], ranges[axis][1] + 1])
+                    .collect();
+                splits.sort_unstable();
+                splits.dedup();
+                splits
+            })
+            .collect();
+        let blocks = |axis: usize, range: [i64; 2]| {
+            let low = splits[axis].binary_search(&range[0]).unwrap();
+            let high = splits[axis].binary_search(&(range[1] + 1)).unwrap();
+            low..high
+        };
+
+        let size = splits.iter().map(|splits| splits.len()).max().unwrap_or(0);
+        let mut lit = vec![false; size * size * size];
+        for (set_on, ranges) in steps {
+            for x in blocks(0, ranges[0]) {
+                for y in blocks(1, ranges[1]) {
+                    for z in blocks(2, ranges[2]) {
+                        lit[(x * size + y) * size + z] = *set_on;
+                    }
+                }
+            }
+        }
+
+        let mut count = 0;
+        for x in 0..splits[0].len().saturating_sub(1) {
+            for y in 0..splits[1].len().saturating_sub(1) {
+                for z in 0..splits[2].len().saturating_sub(1) {
+                    if lit[(x * size + y) * size + z] {
+                        count += (splits[0][x + 1] - splits[0][x])
+                            * (splits[1][y + 1] - splits[1][y])
+                            * (splits[2][z + 1] - splits[2][z]);
+                    }
+                }
+            }
+        }
+
+        count
+    }
+
+    fn brute_force(input: &str, initialization: bool) -> i64 {
+        let mut steps = Vec::new();
+        for line in input.lines() {
+            let (setting, ranges) = line.split_once(' ').unwrap();
+            let mut ranges: Vec<[i64; 2]> = ranges
+                .split(',')
+                .map(|range| {
+                    let (low, high) = range[2..].split_once("..").unwrap();
+                    [low.parse().unwrap(), high.parse().unwrap()]
+                })
+                .collect();
+            if initialization {
+                for range in ranges.iter_mut() {
+                    *range = [range[0].max(-50), range[1].min(50)];
+                }
+                if ranges.iter().any(|range| range[0] > range[1]) {
+                    continue;
+                }
+            }
+            steps.push((setting == "on", [ranges[0], ranges[1], ranges[2]]));
+        }
+
+        count_lit(&steps)
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(seed in any::<u64>(), size in 1..25usize) {
+            let input = Day22::generate(&mut Rng::new(seed), size);
+            let day = Day22::parse(&input).unwrap();
+
+            prop_assert_eq!(day.part_1(), brute_force(&input, true));
+            prop_assert_eq!(day.part_2(), brute_force(&input, false));
+        }
+    }
+}
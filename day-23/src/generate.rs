@@ -0,0 +1,34 @@
+use crate::Day23;
+use common::{Generate, Rng, Solution};
+
+impl Generate for Day23 {
+    // Amphipods, of which there are always eight
+    const SIZE: usize = 8;
+
+    fn generate(rng: &mut Rng, _size: usize) -> String {
+        let mut amphipods: Vec<char> = "AABBCCDD".chars().collect();
+
+        // About one in ten arrangements leaves the amphipods stuck once the
+        // folded rows are added, so shuffle until both parts have a solution
+        loop {
+            rng.shuffle(&mut amphipods);
+
+            let input = format!(
+                "#############\n#...........#\n###{}#{}#{}#{}###\n  #{}#{}#{}#{}#\n  #########\n",
+                amphipods[0],
+                amphipods[1],
+                amphipods[2],
+                amphipods[3],
+                amphipods[4],
+                amphipods[5],
+                amphipods[6],
+                amphipods[7]
+            );
+
+            let day = Day23::parse(&input).unwrap();
+            if day.part_1_state.solve().is_some() && day.part_2_state.solve().is_some() {
+                return input;
+            }
+        }
+    }
+}
@@ -0,0 +1,652 @@
+mod generate;
+
+use common::{astar, Grid, ParseError, Problem, Recording, Search, Simulation, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone)]
+enum Amphipod {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Amphipod {
+    fn from(letter: char) -> Amphipod {
+        match letter {
+            'A' => Amphipod::Amber,
+            'B' => Amphipod::Bronze,
+            'C' => Amphipod::Copper,
+            'D' => Amphipod::Desert,
+            _ => panic!("Unexpected letter {}", letter),
+        }
+    }
+
+    fn as_char(&self) -> char {
+        match self {
+            Amphipod::Amber => 'A',
+            Amphipod::Bronze => 'B',
+            Amphipod::Copper => 'C',
+            Amphipod::Desert => 'D',
+        }
+    }
+
+    fn energy_per_step(&self) -> u32 {
+        match self {
+            Amphipod::Amber => 1,
+            Amphipod::Bronze => 10,
+            Amphipod::Copper => 100,
+            Amphipod::Desert => 1000,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone)]
+enum Place {
+    Unmapped,
+    Wall,
+    Hall(Option<Amphipod>),
+    Doorway,
+    Room((Amphipod, Option<Amphipod>)),
+}
+
+impl fmt::Display for Place {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Place::Unmapped => ' ',
+                Place::Wall => '#',
+                Place::Hall(resident) => {
+                    if let Some(amphipod) = resident {
+                        amphipod.as_char()
+                    } else {
+                        '.'
+                    }
+                }
+                Place::Doorway => 'o',
+                Place::Room((_, resident)) => {
+                    if let Some(amphipod) = resident {
+                        amphipod.as_char()
+                    } else {
+                        '-'
+                    }
+                }
+            }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+struct Map {
+    place_rows: Vec<Vec<Place>>,
+    part_2_flag: bool,
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut result = String::new();
+
+        for place_row in self.place_rows.iter() {
+            for place in place_row.iter() {
+                result.push_str(&format!("{}", place));
+            }
+            result.push('\n');
+        }
+
+        write!(f, "{}", result)
+    }
+}
+
+impl Map {
+    // Check that the input is a burrow of the puzzle's shape, with an empty
+    // hallway and two of each amphipod in the rooms, before building a map
+    fn validate(input: &str) -> Result<(), ParseError> {
+        // Where ? is an amphipod
+        const BURROW: [&str; 5] = [
+            "#############",
+            "#...........#",
+            "###?#?#?#?###",
+            "  #?#?#?#?#",
+            "  #########",
+        ];
+
+        let lines: Vec<&str> = input.lines().collect();
+        let mut problems = Vec::new();
+
+        for (y, line) in lines.iter().enumerate() {
+            let Some(expected_line) = BURROW.get(y) else {
+                problems.push(Problem::new(
+                    y + 1,
+                    1,
+                    line,
+                    line,
+                    "expected the end of the burrow",
+                ));
+                break;
+            };
+            let found = line.trim_end_matches(' ').chars().map(Some).chain([None]);
+            let expected = expected_line.chars().map(Some).chain([None]);
+
+            for (x, (found, expected)) in found.zip(expected).enumerate() {
+                let matches = match expected {
+                    Some('?') => found.is_some_and(|ch| "ABCD".contains(ch)),
+                    _ => found == expected,
+                };
+                if matches {
+                    continue;
+                }
+
+                let message = match expected {
+                    Some('?') => String::from("expected an amphipod, A, B, C or D"),
+                    Some(ch) => format!("expected {:?}", ch),
+                    None => String::from("expected the end of the line"),
+                };
+                let text = found.map(String::from).unwrap_or_default();
+                problems.push(Problem::new(y + 1, x + 1, line, &text, message));
+                break;
+            }
+        }
+        if !problems.is_empty() {
+            return Err(ParseError::new(problems));
+        }
+        if lines.len() < BURROW.len() {
+            return Err(Problem::at_end(input, "expected a hallway and rooms").into());
+        }
+
+        for letter in "ABCD".chars() {
+            let count = input.chars().filter(|&ch| ch == letter).count();
+            if count != 2 {
+                let message = format!("expected two of amphipod {}, found {}", letter, count);
+                return Err(Problem::at_end(input, message).into());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn new(input: &str, part_2_flag: bool) -> Result<Map, ParseError> {
+        Map::validate(input)?;
+
+        let mut lines: Vec<&str> = input.lines().collect();
+
+        if part_2_flag {
+            let mut remaining_lines = lines.split_off(3);
+            lines.append(&mut vec!["  #D#C#B#A#", "  #D#B#A#C#"]);
+            lines.append(&mut remaining_lines);
+        }
+
+        let mut place_rows: Vec<Vec<Place>> = lines
+            .iter()
+            .map(|line| {
+                let mut room_columns: HashSet<usize> = HashSet::new();
+                line.chars()
+                    .enumerate()
+                    .map(|(column, ch)| match ch {
+                        ' ' => Place::Unmapped,
+                        '#' => Place::Wall,
+                        '.' => Place::Hall(None),
+                        'A' | 'B' | 'C' | 'D' => {
+                            room_columns.insert(column);
+                            let target = match room_columns.len() {
+                                1 => Amphipod::Amber,
+                                2 => Amphipod::Bronze,
+                                3 => Amphipod::Copper,
+                                4 => Amphipod::Desert,
+                                _ => panic!("Too many room columns"),
+                            };
+                            let resident = Amphipod::from(ch);
+                            Place::Room((target, Some(resident)))
+                        }
+                        _ => panic!("Unknown place {:?}", ch),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Update place_rows with Doorway locations
+        let mut room_columns = Vec::new();
+        for (column, place) in place_rows[2].iter().enumerate() {
+            if let &Place::Room(_) = place {
+                room_columns.push(column);
+            }
+        }
+        for &room_column in room_columns.iter() {
+            place_rows[1][room_column] = Place::Doorway;
+        }
+
+        Ok(Map {
+            place_rows,
+            part_2_flag,
+        })
+    }
+
+    // Rows of the map may be of different lengths, so the frame is as wide
+    // as the longest, with unmapped places beyond the end of shorter rows
+    fn frame(&self) -> Grid<u8> {
+        let width = self.place_rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut frame = Grid::new(width, self.place_rows.len(), 0);
+
+        for (y, place_row) in self.place_rows.iter().enumerate() {
+            for (x, place) in place_row.iter().enumerate() {
+                frame[(x, y)] = match place {
+                    Place::Unmapped => 0,
+                    Place::Wall => 1,
+                    Place::Hall(Some(amphipod)) | Place::Room((_, Some(amphipod))) => {
+                        3 + *amphipod as u8
+                    }
+                    Place::Hall(None) | Place::Doorway | Place::Room((_, None)) => 2,
+                };
+            }
+        }
+
+        frame
+    }
+
+    fn amphipods_are_organized(&self) -> bool {
+        for place_row in self.place_rows.iter() {
+            for place in place_row.iter() {
+                if let Place::Room((target, resident_option)) = place {
+                    if let Some(resident) = resident_option {
+                        if resident != target {
+                            return false;
+                        }
+                    } else {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    fn visitors_have_departed(&self, x: usize) -> bool {
+        let mut y = 1;
+        loop {
+            y += 1;
+            match self.place_rows[y][x] {
+                Place::Room((_target, None)) => {}
+                Place::Room((target, Some(amphipod))) => {
+                    if amphipod != target {
+                        return false;
+                    }
+                }
+                Place::Wall => {
+                    break;
+                }
+                _ => {
+                    panic!("Unexpected {:?} at ({},{})", self.place_rows[y][x], x, y);
+                }
+            }
+        }
+
+        true
+    }
+
+    fn amphipod_locations(&self) -> Vec<(usize, usize)> {
+        let mut locations: Vec<(usize, usize)> = Vec::new();
+
+        for (y, place_row) in self.place_rows.iter().enumerate() {
+            for (x, place) in place_row.iter().enumerate() {
+                match place {
+                    Place::Hall(Some(_)) => locations.push((x, y)),
+                    Place::Room((_, Some(_))) => locations.push((x, y)),
+                    _ => (),
+                }
+            }
+        }
+
+        locations
+    }
+
+    fn room_x(&self, amphipod: Amphipod) -> usize {
+        for (x, place) in self.place_rows[2].iter().enumerate() {
+            if let Place::Room((target, _)) = place {
+                if amphipod == *target {
+                    return x;
+                }
+            }
+        }
+
+        panic!("Target column not found for {:?}", amphipod);
+    }
+
+    fn hall_path_is_clear(&self, start_x: usize, end_x: usize) -> bool {
+        for x in start_x..=end_x {
+            match self.place_rows[1][x] {
+                Place::Hall(None) => (),
+                Place::Doorway => (),
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    fn available_room_place(&self, x: usize) -> Option<usize> {
+        let mut room_y_option = None;
+        let mut y = 1;
+
+        loop {
+            y += 1;
+            match self.place_rows[y][x] {
+                Place::Room((_, None)) => {
+                    room_y_option = Some(y);
+                }
+                Place::Room((target, Some(amphipod))) => {
+                    if amphipod != target {
+                        return None;
+                    }
+                }
+                Place::Wall => {
+                    break;
+                }
+                _ => {
+                    panic!(
+                        "Unexpected place {:?} at ({},{}) instead of room",
+                        self.place_rows[y][x], x, y
+                    );
+                }
+            }
+        }
+
+        room_y_option
+    }
+
+    fn steps_to_leave_room(&self, amphipod_x: usize, amphipod_y: usize) -> Option<u32> {
+        for y in 2..amphipod_y {
+            match self.place_rows[y][amphipod_x] {
+                Place::Room((_, None)) => (),
+                Place::Room((_, Some(_))) => return None,
+                _ => panic!(
+                    "{:?} at ({},{}) unexpectedly blocks leaving room",
+                    self.place_rows[y][amphipod_x], amphipod_x, y
+                ),
+            }
+        }
+
+        Some(amphipod_y as u32 - 1)
+    }
+
+    fn hall_to_room(&self, x: usize, amphipod: Amphipod) -> Option<(u32, usize, usize)> {
+        let room_x = self.room_x(amphipod);
+
+        if room_x == x {
+            return None;
+        }
+
+        if x < room_x {
+            if !self.hall_path_is_clear(x + 1, room_x) {
+                return None;
+            }
+        } else if !self.hall_path_is_clear(room_x, x - 1) {
+            return None;
+        }
+
+        if let Some(room_y) = self.available_room_place(room_x) {
+            let steps = x.abs_diff(room_x) + room_y - 1;
+            Some((steps as u32, room_x, room_y))
+        } else {
+            None
+        }
+    }
+
+    fn doorway_to_hall(&self, doorway_x: usize) -> Vec<(u32, usize)> {
+        let mut moves = Vec::new();
+
+        let mut x = doorway_x;
+        loop {
+            x -= 1;
+            match self.place_rows[1][x] {
+                Place::Doorway => (),
+                Place::Hall(None) => moves.push(((doorway_x - x) as u32, x)),
+                Place::Hall(Some(_)) => break,
+                Place::Wall => break,
+                _ => panic!("Unexpected place {:?}", self.place_rows[1][x]),
+            }
+        }
+
+        let mut x = doorway_x;
+        loop {
+            x += 1;
+            match self.place_rows[1][x] {
+                Place::Doorway => (),
+                Place::Hall(None) => moves.push(((x - doorway_x) as u32, x)),
+                Place::Hall(Some(_)) => break,
+                Place::Wall => break,
+                _ => panic!("Unexpected place {:?}", self.place_rows[1][x]),
+            }
+        }
+
+        moves
+    }
+
+    fn move_amphipod(
+        &mut self,
+        from_x: usize,
+        from_y: usize,
+        to_x: usize,
+        to_y: usize,
+        caller: u8,
+    ) {
+        let amphipod = match self.place_rows[from_y][from_x] {
+            Place::Hall(Some(amphipod)) => {
+                self.place_rows[from_y][from_x] = Place::Hall(None);
+                amphipod
+            }
+            Place::Room((target, Some(amphipod))) => {
+                self.place_rows[from_y][from_x] = Place::Room((target, None));
+                amphipod
+            }
+            _ => {
+                panic!(
+                    "caller {}: Attempt to move {:?} from ({},{})",
+                    caller, self.place_rows[from_y][from_x], from_x, from_y
+                );
+            }
+        };
+
+        match self.place_rows[to_y][to_x] {
+            Place::Hall(None) => {
+                self.place_rows[to_y][to_x] = Place::Hall(Some(amphipod));
+            }
+            Place::Room((target, None)) => {
+                if amphipod == target {
+                    self.place_rows[to_y][to_x] = Place::Room((target, Some(amphipod)));
+                } else {
+                    panic!(
+                        "caller {}: Attempt to move {:?} to room {:?} at ({},{})",
+                        caller, amphipod, target, to_x, to_y
+                    );
+                }
+            }
+            _ => {
+                panic!(
+                    "caller {}: Attempt to move {:?} to ({},{}) containing {:?}",
+                    caller, amphipod, to_x, to_y, self.place_rows[to_y][to_x]
+                );
+            }
+        }
+    }
+
+    fn estimate_remaining_energy_cost(&self) -> u32 {
+        let mut total_estimate = 0;
+
+        let mut completed_d2r_steps: HashMap<Amphipod, usize> = HashMap::new();
+        completed_d2r_steps.insert(Amphipod::Amber, 0);
+        completed_d2r_steps.insert(Amphipod::Bronze, 0);
+        completed_d2r_steps.insert(Amphipod::Copper, 0);
+        completed_d2r_steps.insert(Amphipod::Desert, 0);
+
+        for &(x, y) in self.amphipod_locations().iter() {
+            match self.place_rows[y][x] {
+                Place::Hall(Some(amphipod)) => {
+                    let steps = x.abs_diff(self.room_x(amphipod));
+                    total_estimate += steps as u32 * amphipod.energy_per_step();
+                }
+                Place::Room((target, Some(amphipod))) => {
+                    if amphipod == target && self.visitors_have_departed(x) {
+                        *completed_d2r_steps.entry(amphipod).or_insert(0) += y - 1;
+                        continue;
+                    }
+                    let r2d_steps = y - 1;
+                    let d2d_steps = x.abs_diff(self.room_x(amphipod));
+                    let steps = r2d_steps + d2d_steps;
+                    total_estimate += steps as u32 * amphipod.energy_per_step();
+                }
+                _ => {
+                    panic!(
+                        "Cannot estimate remaining energy cost for {:?} at ({},{})",
+                        self.place_rows[y][x], x, y
+                    );
+                }
+            }
+        }
+
+        let max_d2r_steps = if self.part_2_flag { 10 } else { 3 };
+
+        // Calculate the cost of entering rooms now that it is known
+        // how many amphipods have already entered
+        for (&amphipod, &steps) in completed_d2r_steps.iter() {
+            total_estimate += (max_d2r_steps - steps as u32) * amphipod.energy_per_step();
+        }
+
+        total_estimate
+    }
+
+    // Each map one move from this one, with the energy spent on the move
+    fn all_valid_moves(&self) -> Vec<(Map, u32)> {
+        let mut moves = Vec::new();
+
+        for &(x, y) in self.amphipod_locations().iter() {
+            match self.place_rows[y][x] {
+                Place::Hall(Some(amphipod)) => {
+                    if let Some((steps, room_x, room_y)) = self.hall_to_room(x, amphipod) {
+                        let mut new_map = self.clone();
+                        new_map.move_amphipod(x, y, room_x, room_y, 1);
+                        moves.push((new_map, steps * amphipod.energy_per_step()));
+                    }
+                }
+                Place::Room((target, Some(amphipod))) => {
+                    if amphipod == target && self.visitors_have_departed(x) {
+                        continue;
+                    }
+
+                    let r2d_steps = if let Some(room_steps) = self.steps_to_leave_room(x, y) {
+                        room_steps
+                    } else {
+                        continue;
+                    };
+
+                    if let Some((h2r_steps, room_x, room_y)) = self.hall_to_room(x, amphipod) {
+                        let mut new_map = self.clone();
+                        new_map.move_amphipod(x, y, room_x, room_y, 2);
+                        let steps = r2d_steps + h2r_steps;
+                        moves.push((new_map, steps * amphipod.energy_per_step()));
+                    }
+
+                    for (d2h_steps, hall_x) in self.doorway_to_hall(x) {
+                        let mut new_map = self.clone();
+                        new_map.move_amphipod(x, y, hall_x, 1, 3);
+                        let steps = r2d_steps + d2h_steps;
+                        moves.push((new_map, steps * amphipod.energy_per_step()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        moves
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    map: Map,
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut result = String::new();
+        result.push_str(&format!("{}", self.map));
+
+        write!(f, "{}", result)
+    }
+}
+
+impl State {
+    fn new(input: &str, part_2_flag: bool) -> Result<State, ParseError> {
+        let map = Map::new(input, part_2_flag)?;
+
+        Ok(State { map })
+    }
+
+    // The least energy to organize the amphipods, if they can be
+    fn solve(&self) -> Option<u32> {
+        astar(self, self.map.clone()).map(|path| path.cost)
+    }
+}
+
+impl Search for State {
+    type State = Map;
+
+    fn successors(&self, map: &Map) -> Vec<(Map, u32)> {
+        map.all_valid_moves()
+    }
+
+    fn is_goal(&self, map: &Map) -> bool {
+        map.amphipods_are_organized()
+    }
+
+    fn heuristic(&self, map: &Map) -> u32 {
+        map.estimate_remaining_energy_cost()
+    }
+}
+
+#[derive(Debug)]
+pub struct Day23 {
+    part_1_state: State,
+    part_2_state: State,
+}
+
+impl Solution for Day23 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Day23, ParseError> {
+        let part_1_state = State::new(input, false)?;
+        let part_2_state = State::new(input, true)?;
+
+        Ok(Day23 {
+            part_1_state,
+            part_2_state,
+        })
+    }
+
+    fn part_1(&self) -> u32 {
+        self.part_1_state.solve().expect("No solution found")
+    }
+
+    fn part_2(&self) -> u32 {
+        self.part_2_state.solve().expect("No solution found")
+    }
+}
+
+// Every move of the cheapest way to organize the amphipods, for part 2
+impl Simulation for Day23 {
+    fn record(&self) -> Recording {
+        let state = &self.part_2_state;
+        let mut recording = Recording::new(7);
+
+        if let Some(path) = astar(state, state.map.clone()) {
+            for map in path.states.iter() {
+                recording.push(map.frame());
+            }
+        }
+
+        recording
+    }
+}
@@ -0,0 +1,44 @@
+use crate::Day24;
+use common::{Generate, Rng};
+use std::fmt::Write;
+
+// MONAD is fourteen sections which each either push a digit (plus an
+// addend) onto a base 26 stack in z, or pop one and compare, with the
+// pushes and pops nested like brackets
+impl Generate for Day24 {
+    // Sections of MONAD, of which there are always fourteen
+    const SIZE: usize = 14;
+
+    fn generate(rng: &mut Rng, _size: usize) -> String {
+        let mut pushes = Vec::new();
+        let mut sections = Vec::new();
+
+        for section in 0..14 {
+            let remaining = 14 - section;
+            let push = pushes.is_empty() || (pushes.len() < remaining && rng.chance(50));
+            if push {
+                let y_addend = rng.range(1, 16);
+                pushes.push(y_addend);
+                sections.push((1, rng.range(10, 15), y_addend));
+            } else {
+                let push_y_addend = pushes.pop().unwrap();
+                let balance = rng.range(-8, 8);
+                sections.push((26, balance - push_y_addend, rng.range(1, 16)));
+            }
+        }
+
+        let mut result = String::new();
+        for (divisor, x_addend, y_addend) in sections {
+            write!(
+                result,
+                "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+                 mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+                 mul y x\nadd z y\n",
+                divisor, x_addend, y_addend
+            )
+            .unwrap();
+        }
+
+        result
+    }
+}
@@ -0,0 +1,633 @@
+// The solutions for both parts of the puzzle were originally obtained
+// through observation and deduction, rather than direct calculation.
+// That deduction is captured in model_number() below, which works out
+// the model numbers from the structure of any puzzle input.
+//
+// Reading about other people's solutions on reddit helped me solve this puzzle.
+// I found especially useful comments by JulienTT, aexl, pedantic_git and
+// relativistic-turtle.
+
+mod generate;
+
+use common::{parse_streamed, parse_text, ParseError, Problem, Solution, Streaming};
+use std::fmt;
+use std::iter::Cycle;
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Copy, Clone)]
+enum Verb {
+    Inp,
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql,
+}
+
+impl fmt::Display for Verb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Verb::Inp => "inp",
+                Verb::Add => "add",
+                Verb::Mul => "mul",
+                Verb::Div => "div",
+                Verb::Mod => "mod",
+                Verb::Eql => "eql",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Variable {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl fmt::Display for Variable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Variable::W => "w",
+                Variable::X => "x",
+                Variable::Y => "y",
+                Variable::Z => "z",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Value {
+    Variable(Variable),
+    Number(i64),
+    Unused,
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>3}",
+            match self {
+                Value::Variable(variable) => format!("{}", variable),
+                Value::Number(number) => format!("{}", number),
+                Value::Unused => String::from(""),
+            }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+struct Alu {
+    w: i64,
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl fmt::Display for Alu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            &format!(
+                "Alu <w: {:>3}, x: {:>3}, y: {:>3}, z: {:>3}>",
+                self.w, self.x, self.y, self.z
+            )
+        )
+    }
+}
+
+impl Alu {
+    fn new(w: i64, x: i64, y: i64, z: i64) -> Alu {
+        Alu { w, x, y, z }
+    }
+
+    fn variable(&mut self, variable: &Variable) -> &mut i64 {
+        match variable {
+            Variable::W => &mut self.w,
+            Variable::X => &mut self.x,
+            Variable::Y => &mut self.y,
+            Variable::Z => &mut self.z,
+        }
+    }
+
+    fn value(&self, value: &Value) -> i64 {
+        match value {
+            Value::Variable(variable) => match variable {
+                Variable::W => self.w,
+                Variable::X => self.x,
+                Variable::Y => self.y,
+                Variable::Z => self.z,
+            },
+            Value::Number(number) => *number,
+            Value::Unused => panic!("Alu should not examine value when unused"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Instruction {
+    verb: Verb,
+    variable: Variable,
+    value: Value,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.verb, self.variable, self.value)
+    }
+}
+
+impl Instruction {
+    fn new(line: &str) -> Result<Instruction, ParseError> {
+        let parts: Vec<&str> = line.split(' ').collect();
+        let mut columns = vec![1];
+        for part in parts.iter() {
+            columns.push(columns[columns.len() - 1] + part.len() + 1);
+        }
+        let problem = |j: usize, message: &str| -> ParseError {
+            Problem::new(1, columns[j], line, parts.get(j).unwrap_or(&""), message).into()
+        };
+
+        let verb = match parts[0] {
+            "inp" => Verb::Inp,
+            "add" => Verb::Add,
+            "mul" => Verb::Mul,
+            "div" => Verb::Div,
+            "mod" => Verb::Mod,
+            "eql" => Verb::Eql,
+            _ => return Err(problem(0, "unknown verb")),
+        };
+
+        let expected_parts = if matches!(verb, Verb::Inp) { 2 } else { 3 };
+        if parts.len() < expected_parts {
+            return Err(Problem::new(1, line.len() + 1, line, "", "missing operand").into());
+        }
+        if parts.len() > expected_parts {
+            return Err(problem(expected_parts, "unexpected operand"));
+        }
+
+        let variable = match parts[1] {
+            "w" => Variable::W,
+            "x" => Variable::X,
+            "y" => Variable::Y,
+            "z" => Variable::Z,
+            _ => return Err(problem(1, "unknown variable")),
+        };
+
+        let value = if parts.len() == 3 {
+            match parts[2] {
+                "w" => Value::Variable(Variable::W),
+                "x" => Value::Variable(Variable::X),
+                "y" => Value::Variable(Variable::Y),
+                "z" => Value::Variable(Variable::Z),
+                _ => Value::Number(parse_text(line, columns[2], parts[2])?),
+            }
+        } else {
+            Value::Unused
+        };
+
+        Ok(Instruction {
+            verb,
+            variable,
+            value,
+        })
+    }
+}
+
+trait Input {
+    fn next_digit(&mut self) -> i64;
+}
+
+#[derive(Debug)]
+struct Executor<'a> {
+    alu: Alu,
+    instructions: &'a [Instruction],
+}
+
+impl<'a> Executor<'a> {
+    fn new(alu: Alu, instructions: &[Instruction]) -> Executor<'_> {
+        Executor { alu, instructions }
+    }
+
+    fn run(&mut self, input: &mut impl Input, debug_flag: bool) {
+        if debug_flag {
+            println!("{}", self.alu);
+        }
+        for instruction in self.instructions.iter() {
+            if debug_flag {
+                print!("{}", instruction);
+            }
+            match instruction.verb {
+                Verb::Inp => {
+                    let a = self.alu.variable(&instruction.variable);
+                    let value = input.next_digit();
+                    *a = value;
+                }
+                Verb::Add => {
+                    let b = self.alu.value(&instruction.value);
+                    let a = self.alu.variable(&instruction.variable);
+                    *a += b;
+                }
+                Verb::Mul => {
+                    let b = self.alu.value(&instruction.value);
+                    let a = self.alu.variable(&instruction.variable);
+                    *a *= b;
+                }
+                Verb::Div => {
+                    let b = self.alu.value(&instruction.value);
+                    let a = self.alu.variable(&instruction.variable);
+                    *a /= b;
+                }
+                Verb::Mod => {
+                    let b = self.alu.value(&instruction.value);
+                    let a = self.alu.variable(&instruction.variable);
+                    *a %= b;
+                }
+                Verb::Eql => {
+                    let b = self.alu.value(&instruction.value);
+                    let a = self.alu.variable(&instruction.variable);
+                    if *a == b {
+                        *a = 1;
+                    } else {
+                        *a = 0;
+                    }
+                }
+            }
+            if debug_flag {
+                println!(" => {}", self.alu);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ModelNumber {
+    digits: Vec<i64>,
+    index: usize,
+}
+
+impl Input for ModelNumber {
+    fn next_digit(&mut self) -> i64 {
+        let digit = self.digits[self.index];
+        self.index += 1;
+
+        digit
+    }
+}
+
+impl fmt::Display for ModelNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut result = String::new();
+
+        for digit in self.digits.iter() {
+            result.push_str(&format!("{}", digit));
+        }
+
+        write!(f, "{}", result)
+    }
+}
+
+// The puzzle input repeats, almost verbatim, 18 instructions
+// 14 times. The only differences are the values for the
+// fifth, sixth, and sixteenth instructions in each section.
+fn section_values(instructions: &[Instruction]) -> Vec<(i64, i64, i64)> {
+    let mut section_values = Vec::new();
+
+    for section in 0..14 {
+        let mut values = Vec::new();
+        for offset in [4, 5, 15] {
+            let value = instructions[section * 18 + offset].value;
+            if let Value::Number(number) = value {
+                values.push(number);
+            } else {
+                panic!(
+                    "Non-number value {:?} for section {} at offset {}",
+                    value, section, offset
+                );
+            }
+        }
+        section_values.push((values[0], values[1], values[2]));
+    }
+
+    section_values
+}
+
+// Sections whose fifth value is 1 "push" and sections whose fifth value
+// is 26 "pop". Each push is reversed by a pop, and pairs of sections are
+// matched by ordering, like brackets. The popping section's input must
+// equal the pushing section's input plus the pushing section's sixteenth
+// value plus the popping section's sixth value. Making the input of the
+// earlier section of each pair as large (or small) as possible gives the
+// largest (or smallest) model number.
+fn model_number(instructions: &[Instruction], largest: bool) -> ModelNumber {
+    let mut digits = vec![0; 14];
+    let mut pushes = Vec::new();
+
+    for (section, &(divisor, x_addend, y_addend)) in section_values(instructions).iter().enumerate()
+    {
+        if divisor == 1 {
+            pushes.push((section, y_addend));
+            continue;
+        }
+
+        let (push_section, push_y_addend) = pushes.pop().unwrap();
+        let balance = push_y_addend + x_addend;
+        let (push_digit, pop_digit) = match (largest, balance >= 0) {
+            (true, true) => (9 - balance, 9),
+            (true, false) => (9, 9 + balance),
+            (false, true) => (1, 1 + balance),
+            (false, false) => (1 - balance, 1),
+        };
+        digits[push_section] = push_digit;
+        digits[section] = pop_digit;
+    }
+
+    let model_number = ModelNumber { digits, index: 0 };
+
+    // Confirm that MONAD accepts the model number
+    let alu = Alu::new(0, 0, 0, 0);
+    let mut executor = Executor::new(alu, instructions);
+    executor.run(&mut model_number.clone(), false);
+    assert_eq!(executor.alu.z, 0, "MONAD rejected {}", model_number);
+
+    model_number
+}
+
+// Each section of MONAD, with its three numbers left out
+const SECTION: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z ", "add x ", "eql x w", "eql x 0", "mul y 0",
+    "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y ", "mul y x",
+    "add z y",
+];
+const SECTIONS: usize = 14;
+
+#[derive(Debug, Default)]
+pub struct Day24 {
+    instructions: Vec<Instruction>,
+    // The sections whose digits are on the stack in z as MONAD is read,
+    // with the y addends they pushed
+    pushes: Vec<(usize, i64)>,
+}
+
+impl Solution for Day24 {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Day24, ParseError> {
+        parse_streamed(input)
+    }
+
+    // Part 1 requires the largest model number
+    fn part_1(&self) -> String {
+        model_number(&self.instructions, true).to_string()
+    }
+
+    // Part 2 requires the smallest model number
+    fn part_2(&self) -> String {
+        model_number(&self.instructions, false).to_string()
+    }
+}
+
+impl Day24 {
+    // Check that an instruction fits the shape of MONAD which
+    // model_number() relies on, given the instructions before it
+    fn check(&mut self, instruction: &Instruction, line: &str) -> Result<(), String> {
+        let index = self.instructions.len();
+        if index == SECTIONS * SECTION.len() {
+            return Err(format!(
+                "expected MONAD to end after {} sections of {} instructions",
+                SECTIONS,
+                SECTION.len()
+            ));
+        }
+
+        let (section, offset) = (index / SECTION.len(), index % SECTION.len());
+        let expected = SECTION[offset];
+        let number = match instruction.value {
+            Value::Number(number) if expected.ends_with(' ') => number,
+            _ if line == expected => return Ok(()),
+            _ => {
+                let expected = format!(
+                    "{}{}",
+                    expected,
+                    if expected.ends_with(' ') { "N" } else { "" }
+                );
+                return Err(format!("expected {:?} in this section of MONAD", expected));
+            }
+        };
+        if !line.starts_with(expected) {
+            return Err(format!(
+                "expected {:?} and a number in this section of MONAD",
+                expected
+            ));
+        }
+
+        let pushing = |day: &Day24| {
+            matches!(
+                day.instructions[section * SECTION.len() + 4].value,
+                Value::Number(1)
+            )
+        };
+        let no_push = "there is no digit for this section to pop";
+        let small_addend =
+            "expected an x addend of at least 10 in a section which pushes, so that it always does";
+        match offset {
+            4 if number == 1 => Ok(()),
+            4 if number != 26 => Err(String::from("expected MONAD to divide z by 1 or 26")),
+            4 if self.pushes.is_empty() => Err(String::from(no_push)),
+            4 => Ok(()),
+            5 if pushing(self) && number < 10 => Err(String::from(small_addend)),
+            5 if pushing(self) => Ok(()),
+            5 => {
+                let (push_section, push_y_addend) = self.pushes.pop().ok_or(no_push)?;
+                let balance = push_y_addend.saturating_add(number);
+                if !(-8..=8).contains(&balance) {
+                    return Err(format!(
+                        "no digits balance this section with section {}, which differ by {}",
+                        push_section + 1,
+                        balance
+                    ));
+                }
+                Ok(())
+            }
+            _ if !(0..=16).contains(&number) => Err(String::from(
+                "expected a y addend from 0 to 16, so that each digit pushed fits in base 26",
+            )),
+            _ => {
+                if pushing(self) {
+                    self.pushes.push((section, number));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Streaming for Day24 {
+    fn parse_line(&mut self, line: &str) -> Result<(), ParseError> {
+        let instruction = Instruction::new(line)?;
+        let checked = self.check(&instruction, line);
+        self.instructions.push(instruction);
+        checked.map_err(|message| Problem::new(1, 1, line, line, message))?;
+
+        Ok(())
+    }
+
+    fn finish(self, lines: usize) -> Result<Day24, ParseError> {
+        let expected = SECTIONS * SECTION.len();
+        if self.instructions.len() < expected {
+            let message = format!(
+                "expected {} sections of {} instructions, found {} instructions",
+                SECTIONS,
+                SECTION.len(),
+                self.instructions.len()
+            );
+            return Err(Problem::after_lines(lines, message).into());
+        }
+        if let Some(&(section, _)) = self.pushes.first() {
+            let message = format!(
+                "the digit pushed by section {} is never popped",
+                section + 1
+            );
+            return Err(Problem::after_lines(lines, message).into());
+        }
+
+        Ok(self)
+    }
+}
+
+// Below is some more code I wrote while working on this problem.
+// This code was not needed for finding the solutions.
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct ModelNumberSource {
+    internals: Vec<(i64, Cycle<RangeInclusive<i64>>)>,
+    model_number: ModelNumber,
+}
+
+impl ModelNumberSource {
+    #[allow(dead_code)]
+    fn new() -> ModelNumberSource {
+        let mut internals = vec![(0, (1..=9).cycle()); 14];
+        let mut digits = Vec::new();
+
+        for (digit, cycle) in internals.iter_mut() {
+            *digit = cycle.next().unwrap();
+            digits.push(10 - *digit);
+        }
+
+        let model_number = ModelNumber { digits, index: 0 };
+
+        ModelNumberSource {
+            internals,
+            model_number,
+        }
+    }
+
+    #[allow(dead_code)]
+    fn advance(&mut self) {
+        let mut cycle_flag = true;
+        let mut digits = Vec::new();
+
+        for (digit, cycle) in self.internals.iter_mut().rev() {
+            if cycle_flag {
+                if *digit != 9 {
+                    cycle_flag = false;
+                }
+                *digit = cycle.next().unwrap();
+            }
+            digits.push(10 - *digit);
+        }
+        digits = digits.into_iter().rev().collect();
+
+        self.model_number = ModelNumber { digits, index: 0 };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse_lines;
+
+    #[test]
+    fn sample_1() {
+        use crate::*;
+
+        let input = "inp x\nmul x -1";
+        let instructions: Vec<Instruction> = parse_lines(input, Instruction::new).unwrap();
+        let alu = Alu::new(0, 0, 0, 0);
+        let mut executor = Executor::new(alu, &instructions);
+        let mut model_number = ModelNumber {
+            digits: vec![7],
+            index: 0,
+        };
+        executor.run(&mut model_number, false);
+        assert_eq!(
+            executor.alu,
+            Alu {
+                w: 0,
+                x: -7,
+                y: 0,
+                z: 0
+            }
+        );
+    }
+
+    #[test]
+    fn sample_2() {
+        use crate::*;
+
+        let input = "inp z\ninp x\nmul z 3\neql z x";
+        let instructions: Vec<Instruction> = parse_lines(input, Instruction::new).unwrap();
+        let alu = Alu::new(0, 0, 0, 0);
+        let mut executor = Executor::new(alu, &instructions);
+        let mut model_number = ModelNumber {
+            digits: vec![3, 9],
+            index: 0,
+        };
+        executor.run(&mut model_number, false);
+        assert_eq!(
+            executor.alu,
+            Alu {
+                w: 0,
+                x: 9,
+                y: 0,
+                z: 1
+            }
+        );
+    }
+
+    #[test]
+    fn sample_3() {
+        use crate::*;
+
+        let input = "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\ndiv w 2\nmod w 2";
+        let instructions: Vec<Instruction> = parse_lines(input, Instruction::new).unwrap();
+        let alu = Alu::new(0, 0, 0, 0);
+        let mut executor = Executor::new(alu, &instructions);
+        let mut model_number = ModelNumber {
+            digits: vec![6],
+            index: 0,
+        };
+        executor.run(&mut model_number, false);
+        assert_eq!(
+            executor.alu,
+            Alu {
+                w: 0,
+                x: 1,
+                y: 1,
+                z: 0
+            }
+        );
+    }
+}
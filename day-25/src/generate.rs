@@ -0,0 +1,34 @@
+use crate::Day25;
+use common::{Generate, Rng};
+
+impl Generate for Day25 {
+    // Height of the sea floor, which is two wider than it is high
+    const SIZE: usize = 137;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let (width, height) = (size + 2, size.max(1));
+        let (wall_x, wall_y) = (rng.below(width), rng.below(height));
+
+        // A full row of east movers and a full column of south movers can
+        // never move, so every other sea cucumber eventually piles up behind
+        // them, rather than wrapping around the sea floor forever
+        let map = rng.char_map(width, height, |rng| match rng.below(5) {
+            0 | 1 => '.',
+            2 | 3 => '>',
+            _ => 'v',
+        });
+        let mut cells = map.into_bytes();
+        for y in 0..height {
+            for x in 0..width {
+                let cell = &mut cells[y * (width + 1) + x];
+                if y == wall_y {
+                    *cell = b'>';
+                } else if x == wall_x {
+                    *cell = b'v';
+                }
+            }
+        }
+
+        String::from_utf8(cells).unwrap()
+    }
+}
@@ -0,0 +1,171 @@
+mod generate;
+
+use common::{Grid, NoAnswer, ParseError, Recording, Simulation, Solution};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    East,
+    South,
+    Empty,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Location::East => '>',
+                Location::South => 'v',
+                Location::Empty => '.',
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Region {
+    location_rows: Grid<Location>,
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.location_rows)
+    }
+}
+
+impl Region {
+    fn new(input: &str) -> Result<Region, ParseError> {
+        let location_rows = Grid::parse(input, "'>', 'v' or '.'", |ch| match ch {
+            '>' => Some(Location::East),
+            'v' => Some(Location::South),
+            '.' => Some(Location::Empty),
+            _ => None,
+        })?;
+
+        Ok(Region { location_rows })
+    }
+
+    // Move every sea cucumber of the `herd` one step of `offset` at once,
+    // if the location ahead of it is empty, wrapping around the edges
+    fn move_herd(&mut self, herd: Location, offset: (isize, isize)) -> bool {
+        let mut moved_location_rows = self.location_rows.clone();
+        let mut updated = false;
+
+        for (position, &location) in self.location_rows.iter() {
+            if location != herd {
+                continue;
+            }
+
+            let next_position = self.location_rows.wrapping_step(position, offset);
+            if self.location_rows[next_position] == Location::Empty {
+                moved_location_rows[position] = Location::Empty;
+                moved_location_rows[next_position] = herd;
+                updated = true;
+            }
+        }
+
+        self.location_rows = moved_location_rows;
+
+        updated
+    }
+
+    fn frame(&self) -> Grid<u8> {
+        self.location_rows.map(|location| match location {
+            Location::Empty => 0,
+            Location::East => 1,
+            Location::South => 2,
+        })
+    }
+
+    fn step(&mut self) -> bool {
+        let east_updated = self.move_herd(Location::East, (1, 0));
+        let south_updated = self.move_herd(Location::South, (0, 1));
+
+        east_updated || south_updated
+    }
+
+    // Step until no sea cucumber moves, visiting the region after each
+    // step which moved any, and give the number of steps taken. The sea
+    // cucumbers may instead fall into a cycle of steps in which they keep
+    // moving forever, which is found by Brent's method, comparing each
+    // region with one saved at the last power of two steps
+    fn settle(&self, mut visit: impl FnMut(&Region)) -> Result<u32, NoAnswer> {
+        let mut region = self.clone();
+        let mut saved = region.location_rows.clone();
+        let mut power = 1;
+        let mut length = 0;
+
+        let mut step = 0;
+        loop {
+            step += 1;
+
+            if !region.step() {
+                return Ok(step);
+            }
+            visit(&region);
+            if region.location_rows == saved {
+                return Err(NoAnswer::new("the sea cucumbers never stop moving"));
+            }
+
+            length += 1;
+            if length == power {
+                saved = region.location_rows.clone();
+                power *= 2;
+                length = 0;
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Day25 {
+    region: Region,
+}
+
+impl Solution for Day25 {
+    type Part1 = Result<u32, NoAnswer>;
+    type Part2 = ();
+
+    fn parse(input: &str) -> Result<Day25, ParseError> {
+        let region = Region::new(input)?;
+
+        Ok(Day25 { region })
+    }
+
+    fn part_1(&self) -> Result<u32, NoAnswer> {
+        self.region.settle(|_| {})
+    }
+
+    // Day 25 has no second puzzle
+    fn part_2(&self) {}
+}
+
+// Every step until the sea cucumbers stop moving
+impl Simulation for Day25 {
+    fn record(&self) -> Recording {
+        let mut recording = Recording::new(3);
+
+        // Sea cucumbers which never stop are recorded until their steps
+        // repeat
+        recording.push(self.region.frame());
+        let _ = self.region.settle(|region| recording.push(region.frame()));
+
+        recording
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn never_stop() {
+        let solution = Day25::parse("...\n.>.\n...\n").unwrap();
+        assert!(solution.part_1().is_err());
+
+        let solution = Day25::parse(">>>\n.v.\n...\n").unwrap();
+        assert_eq!(solution.part_1(), Ok(2));
+    }
+}
@@ -0,0 +1,95 @@
+// Benchmarks of each day's parse, part 1 and part 2, on the bundled sample
+// and on a generated input the size of a personal puzzle input:
+//
+//     cargo bench -p runner                          # every day
+//     cargo bench -p runner -- day-15/               # one day
+//     cargo bench -p runner -- --save-baseline main  # keep a named run
+//     cargo bench -p runner -- --baseline main       # compare with it
+//
+// Criterion keeps its results in target/criterion, and reports the change
+// from the previous run (or from the named baseline) for each benchmark.
+
+use common::{Generate, Rng};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+
+// Days which take long enough per iteration that the default of 100
+// samples would take minutes
+const SLOW_DAYS: [u8; 4] = [15, 17, 19, 23];
+
+fn sample(day: u8) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day-{:02}", day))
+        .join("samples")
+        .join("sample.txt");
+
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e))
+}
+
+// Generated from a fixed seed, so that results are comparable between runs
+fn synthetic<S: Generate>(day: u8) -> String {
+    S::generate(&mut Rng::new(u64::from(day)), S::SIZE)
+}
+
+fn bench_day<S: Generate>(c: &mut Criterion, day: u8) {
+    let inputs = [("sample", sample(day)), ("synthetic", synthetic::<S>(day))];
+
+    let mut group = c.benchmark_group(format!("day-{:02}", day));
+    if SLOW_DAYS.contains(&day) {
+        group.sample_size(10);
+    }
+
+    for (name, input) in inputs.iter() {
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter(|| S::parse(black_box(input)).unwrap())
+        });
+
+        let solution = S::parse(input).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("part-1", name),
+            &solution,
+            |b, solution| b.iter(|| solution.part_1()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("part-2", name),
+            &solution,
+            |b, solution| b.iter(|| solution.part_2()),
+        );
+    }
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day_01::Day01>(c, 1);
+    bench_day::<day_02::Day02>(c, 2);
+    bench_day::<day_03::Day03>(c, 3);
+    bench_day::<day_04::Day04>(c, 4);
+    bench_day::<day_05::Day05>(c, 5);
+    bench_day::<day_06::Day06>(c, 6);
+    bench_day::<day_07::Day07>(c, 7);
+    bench_day::<day_08::Day08>(c, 8);
+    bench_day::<day_09::Day09>(c, 9);
+    bench_day::<day_10::Day10>(c, 10);
+    bench_day::<day_11::Day11>(c, 11);
+    bench_day::<day_12::Day12>(c, 12);
+    bench_day::<day_13::Day13>(c, 13);
+    bench_day::<day_14::Day14>(c, 14);
+    bench_day::<day_15::Day15>(c, 15);
+    bench_day::<day_16::Day16>(c, 16);
+    bench_day::<day_17::Day17>(c, 17);
+    bench_day::<day_18::Day18>(c, 18);
+    bench_day::<day_19::Day19>(c, 19);
+    bench_day::<day_20::Day20>(c, 20);
+    bench_day::<day_21::Day21>(c, 21);
+    bench_day::<day_22::Day22>(c, 22);
+    bench_day::<day_23::Day23>(c, 23);
+    bench_day::<day_24::Day24>(c, 24);
+    bench_day::<day_25::Day25>(c, 25);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
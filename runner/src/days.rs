@@ -0,0 +1,310 @@
+use crate::pool::Pool;
+use crate::profile::Phase;
+use common::{
+    parse_stream, Answer, Generate, ParseError, ReadError, Recording, Rng, Simulation, Solution,
+    Streaming,
+};
+use std::io::{self, BufRead, BufReader, Read};
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    pub peak_bytes: usize,
+}
+
+#[derive(Debug)]
+pub struct DayResult {
+    pub day: u8,
+    pub input_hash: u64,
+    pub parse_elapsed: Duration,
+    pub parse_peak_bytes: usize,
+    pub parts: Vec<PartResult>,
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+// A 64-bit FNV-1a hash, which identifies an input without depending on the
+// standard library's unspecified hashing algorithm, continued from `hash`
+// over more of the input
+fn input_hash(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+// Hashes the bytes of an input as they are read
+struct HashingReader<'a> {
+    inner: &'a mut dyn Read,
+    hash: u64,
+}
+
+impl Read for HashingReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        self.hash = input_hash(self.hash, &buf[..count]);
+
+        Ok(count)
+    }
+}
+
+// Solve the parts on threads of their own, when the pool has them spare
+fn solve_parts<S: Solution + Sync>(solution: &S, parts: &[u8], pool: &Pool) -> Vec<PartResult> {
+    pool.map(parts.to_vec(), |part| {
+        let phase = Phase::start();
+        let start = Instant::now();
+        let answer = match part {
+            1 => solution.part_1().into(),
+            2 => solution.part_2().into(),
+            _ => panic!("Unknown part {}", part),
+        };
+        let elapsed = start.elapsed();
+
+        PartResult {
+            part,
+            answer,
+            elapsed,
+            peak_bytes: phase.peak_bytes(),
+        }
+    })
+}
+
+type Solver = fn(u8, &str, &[u8], &Pool) -> Result<DayResult, ParseError>;
+
+fn solve<S: Solution + Sync>(
+    day: u8,
+    input: &str,
+    parts: &[u8],
+    pool: &Pool,
+) -> Result<DayResult, ParseError> {
+    let phase = Phase::start();
+    let start = Instant::now();
+    let solution = S::parse(input)?;
+    let parse_elapsed = start.elapsed();
+    let parse_peak_bytes = phase.peak_bytes();
+
+    Ok(DayResult {
+        day,
+        input_hash: input_hash(FNV_OFFSET, input.as_bytes()),
+        parse_elapsed,
+        parse_peak_bytes,
+        parts: solve_parts(&solution, parts, pool),
+    })
+}
+
+fn solver(day: u8) -> Option<Solver> {
+    let solver: Solver = match day {
+        1 => solve::<day_01::Day01>,
+        2 => solve::<day_02::Day02>,
+        3 => solve::<day_03::Day03>,
+        4 => solve::<day_04::Day04>,
+        5 => solve::<day_05::Day05>,
+        6 => solve::<day_06::Day06>,
+        7 => solve::<day_07::Day07>,
+        8 => solve::<day_08::Day08>,
+        9 => solve::<day_09::Day09>,
+        10 => solve::<day_10::Day10>,
+        11 => solve::<day_11::Day11>,
+        12 => solve::<day_12::Day12>,
+        13 => solve::<day_13::Day13>,
+        14 => solve::<day_14::Day14>,
+        15 => solve::<day_15::Day15>,
+        16 => solve::<day_16::Day16>,
+        17 => solve::<day_17::Day17>,
+        18 => solve::<day_18::Day18>,
+        19 => solve::<day_19::Day19>,
+        20 => solve::<day_20::Day20>,
+        21 => solve::<day_21::Day21>,
+        22 => solve::<day_22::Day22>,
+        23 => solve::<day_23::Day23>,
+        24 => solve::<day_24::Day24>,
+        25 => solve::<day_25::Day25>,
+        _ => return None,
+    };
+
+    Some(solver)
+}
+
+pub fn run(day: u8, input: &str, parts: &[u8], pool: &Pool) -> Result<DayResult, ParseError> {
+    let solver = solver(day).unwrap_or_else(|| panic!("No solution for day {}", day));
+
+    solver(day, input, parts, pool)
+}
+
+type Streamer = fn(u8, &mut dyn Read, &[u8], &Pool) -> Result<DayResult, ReadError>;
+
+// Parse the input a line at a time as it is read, rather than reading all of
+// it first, so the time taken to parse includes the time taken to read
+fn stream<S: Streaming + Sync>(
+    day: u8,
+    input: &mut dyn Read,
+    parts: &[u8],
+    pool: &Pool,
+) -> Result<DayResult, ReadError> {
+    let mut reader = HashingReader {
+        inner: input,
+        hash: FNV_OFFSET,
+    };
+
+    let phase = Phase::start();
+    let start = Instant::now();
+    let solution: S = parse_stream(BufReader::new(&mut reader).lines())?;
+    let parse_elapsed = start.elapsed();
+    let parse_peak_bytes = phase.peak_bytes();
+
+    Ok(DayResult {
+        day,
+        input_hash: reader.hash,
+        parse_elapsed,
+        parse_peak_bytes,
+        parts: solve_parts(&solution, parts, pool),
+    })
+}
+
+// The days whose inputs can be parsed as they are read
+pub fn streamer(day: u8) -> Option<Streamer> {
+    let streamer: Streamer = match day {
+        1 => stream::<day_01::Day01>,
+        2 => stream::<day_02::Day02>,
+        3 => stream::<day_03::Day03>,
+        5 => stream::<day_05::Day05>,
+        8 => stream::<day_08::Day08>,
+        10 => stream::<day_10::Day10>,
+        18 => stream::<day_18::Day18>,
+        22 => stream::<day_22::Day22>,
+        24 => stream::<day_24::Day24>,
+        _ => return None,
+    };
+
+    Some(streamer)
+}
+
+type Recorder = fn(&str) -> Result<Recording, ParseError>;
+
+fn record<S: Simulation>(input: &str) -> Result<Recording, ParseError> {
+    Ok(S::parse(input)?.record())
+}
+
+// The days with a simulation to render
+pub fn recorder(day: u8) -> Option<Recorder> {
+    let recorder: Recorder = match day {
+        11 => record::<day_11::Day11>,
+        13 => record::<day_13::Day13>,
+        20 => record::<day_20::Day20>,
+        23 => record::<day_23::Day23>,
+        25 => record::<day_25::Day25>,
+        _ => return None,
+    };
+
+    Some(recorder)
+}
+
+type Generator = fn(u64, Option<usize>) -> String;
+
+fn generate_input<S: Generate>(seed: u64, size: Option<usize>) -> String {
+    S::generate(&mut Rng::new(seed), size.unwrap_or(S::SIZE))
+}
+
+fn generator(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => generate_input::<day_01::Day01>,
+        2 => generate_input::<day_02::Day02>,
+        3 => generate_input::<day_03::Day03>,
+        4 => generate_input::<day_04::Day04>,
+        5 => generate_input::<day_05::Day05>,
+        6 => generate_input::<day_06::Day06>,
+        7 => generate_input::<day_07::Day07>,
+        8 => generate_input::<day_08::Day08>,
+        9 => generate_input::<day_09::Day09>,
+        10 => generate_input::<day_10::Day10>,
+        11 => generate_input::<day_11::Day11>,
+        12 => generate_input::<day_12::Day12>,
+        13 => generate_input::<day_13::Day13>,
+        14 => generate_input::<day_14::Day14>,
+        15 => generate_input::<day_15::Day15>,
+        16 => generate_input::<day_16::Day16>,
+        17 => generate_input::<day_17::Day17>,
+        18 => generate_input::<day_18::Day18>,
+        19 => generate_input::<day_19::Day19>,
+        20 => generate_input::<day_20::Day20>,
+        21 => generate_input::<day_21::Day21>,
+        22 => generate_input::<day_22::Day22>,
+        23 => generate_input::<day_23::Day23>,
+        24 => generate_input::<day_24::Day24>,
+        25 => generate_input::<day_25::Day25>,
+        _ => return None,
+    };
+
+    Some(generator)
+}
+
+// A random input for the day, of `size` or else the size of a personal
+// puzzle input
+pub fn generate(day: u8, seed: u64, size: Option<usize>) -> String {
+    let generator = generator(day).unwrap_or_else(|| panic!("No generator for day {}", day));
+
+    generator(seed, size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, run};
+    use crate::pool::Pool;
+    use common::parse_arbitrary;
+    use std::fs;
+    use std::path::Path;
+
+    type Target = (&'static str, fn(&[u8]));
+
+    // Replay the fuzzing corpus without libFuzzer, so that inputs which
+    // once made a parser panic stay fixed
+    #[test]
+    fn fuzz_corpus() {
+        let corpus = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("fuzz")
+            .join("corpus");
+        let targets: [Target; 14] = [
+            ("day_02", parse_arbitrary::<day_02::Day02>),
+            ("day_04", parse_arbitrary::<day_04::Day04>),
+            ("day_05", parse_arbitrary::<day_05::Day05>),
+            ("day_12", parse_arbitrary::<day_12::Day12>),
+            ("day_13", parse_arbitrary::<day_13::Day13>),
+            ("day_14", parse_arbitrary::<day_14::Day14>),
+            ("day_16", parse_arbitrary::<day_16::Day16>),
+            ("day_17", parse_arbitrary::<day_17::Day17>),
+            ("day_18", parse_arbitrary::<day_18::Day18>),
+            ("day_19", parse_arbitrary::<day_19::Day19>),
+            ("day_20", parse_arbitrary::<day_20::Day20>),
+            ("day_22", parse_arbitrary::<day_22::Day22>),
+            ("day_23", parse_arbitrary::<day_23::Day23>),
+            ("day_24", parse_arbitrary::<day_24::Day24>),
+        ];
+
+        for (target, parse) in targets {
+            let directory = corpus.join(target);
+            let entries = fs::read_dir(&directory)
+                .unwrap_or_else(|e| panic!("cannot read {}: {}", directory.display(), e));
+            for entry in entries {
+                parse(&fs::read(entry.unwrap().path()).unwrap());
+            }
+        }
+    }
+
+    // Generators clamp small sizes to the smallest inputs their days accept
+    #[test]
+    fn small_generated_inputs_parse() {
+        let pool = Pool::new(1);
+
+        for day in 1..=25 {
+            for size in 0..=2 {
+                let input = generate(day, 1, Some(size));
+                if let Err(e) = run(day, &input, &[], &pool) {
+                    panic!("day {} at size {}:\n{}", day, size, e);
+                }
+            }
+        }
+    }
+}
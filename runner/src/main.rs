@@ -0,0 +1,224 @@
+mod days;
+mod options;
+mod pool;
+mod profile;
+mod report;
+mod verify;
+
+use common::{Answer, ReadError};
+use options::{Format, Options, Render, Target, USAGE};
+use pool::Pool;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+#[global_allocator]
+static ALLOCATOR: profile::CountingAllocator = profile::CountingAllocator;
+
+// A day's input, and what came of solving it
+type Solved = (PathBuf, Result<days::DayResult, ReadError>);
+
+// The pause between frames of a terminal replay
+const FRAME_DELAY: Duration = Duration::from_millis(100);
+
+// Read or generate one day's input. Failing to is reported as that day's
+// error, so that the other days still run
+fn read_input(options: &Options, day: u8) -> Result<(PathBuf, String), String> {
+    if let Some(seed) = options.generate {
+        let input = days::generate(day, seed, options.size);
+
+        // Save the input, when given a directory, so that problems with it
+        // can be reproduced
+        let Some(directory) = &options.input else {
+            let path = PathBuf::from(format!("<day {} from seed {}>", day, seed));
+            return Ok((path, input));
+        };
+        let path = directory.join(format!("day-{:02}.txt", day));
+        fs::create_dir_all(directory)
+            .and_then(|_| fs::write(&path, &input))
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+
+        return Ok((path, input));
+    }
+
+    let path = options.input_path(day)?;
+    let mut input = String::new();
+    open_input(&path)
+        .and_then(|mut reader| reader.read_to_string(&mut input))
+        .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+
+    Ok((path, input))
+}
+
+// The input file, or standard input for -
+fn open_input(path: &Path) -> io::Result<Box<dyn Read>> {
+    if path == Path::new("-") {
+        Ok(Box::new(io::stdin()))
+    } else {
+        Ok(Box::new(File::open(path)?))
+    }
+}
+
+// Solve one day, parsing its input as it is read when the day allows it
+// rather than reading the whole input first
+fn solve(options: &Options, day: u8, pool: &Pool) -> Result<Solved, String> {
+    let streamer = days::streamer(day).filter(|_| options.generate.is_none());
+    let Some(streamer) = streamer else {
+        let (path, input) = read_input(options, day)?;
+        let result = days::run(day, &input, &options.parts, pool).map_err(ReadError::from);
+        return Ok((path, result));
+    };
+
+    let path = options.input_path(day)?;
+    let result = open_input(&path)
+        .map_err(ReadError::from)
+        .and_then(|mut reader| streamer(day, &mut *reader, &options.parts, pool));
+
+    Ok((path, result))
+}
+
+// Record and render the simulation of each selected day, rather than
+// solving them
+fn render(options: &Options, render: &Render) -> bool {
+    let mut failed = false;
+
+    for &day in options.days.iter() {
+        let Some(recorder) = days::recorder(day) else {
+            eprintln!("error: day {} has no simulation to render", day);
+            failed = true;
+            continue;
+        };
+        let (path, input) = match read_input(options, day) {
+            Ok(read) => read,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                failed = true;
+                continue;
+            }
+        };
+
+        let recording = match recorder(&input) {
+            Ok(recording) => recording,
+            Err(e) => {
+                eprint!("{}", e.with_file(&path));
+                failed = true;
+                continue;
+            }
+        };
+
+        let result = match &render.target {
+            Target::Terminal => common::replay(
+                &recording,
+                &mut io::stdout(),
+                render.scale,
+                &render.palette,
+                FRAME_DELAY,
+            ),
+            Target::Images(directory) => common::write_images(
+                &recording,
+                directory,
+                &format!("day-{:02}", day),
+                render.scale,
+                &render.palette,
+            )
+            .map(|paths| {
+                eprintln!(
+                    "Wrote {} frames of day {} to {}",
+                    paths.len(),
+                    day,
+                    directory.display()
+                );
+            }),
+        };
+        if let Err(e) = result {
+            eprintln!("error: cannot render day {}: {}", day, e);
+            failed = true;
+        }
+    }
+
+    !failed
+}
+
+fn main() {
+    let options = Options::parse(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("error: {}\n\n{}", e, USAGE);
+        process::exit(2);
+    });
+
+    if let Some(render_options) = &options.render {
+        if !render(&options, render_options) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let mut day_results = Vec::new();
+    let mut checks = Vec::new();
+    let mut failed = false;
+
+    // Solve the days concurrently, and then report on them in order
+    let pool = Pool::new(options.jobs);
+    let solved = pool.map(options.days.clone(), |day| solve(&options, day, &pool));
+
+    for solved in solved {
+        // Report a missing, malformed or unreadable input, and carry on
+        // with the remaining days
+        let (path, result) = match solved {
+            Ok(solved) => solved,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                failed = true;
+                continue;
+            }
+        };
+        match result {
+            Ok(day_result) => {
+                if options.verify.is_some() {
+                    checks.extend(verify::check(&day_result, |part| {
+                        options.answer_path(&path, part)
+                    }));
+                }
+                // An input may parse and still have no answer
+                if day_result
+                    .parts
+                    .iter()
+                    .any(|part_result| matches!(part_result.answer, Answer::Unsolvable(_)))
+                {
+                    failed = true;
+                }
+                day_results.push(day_result);
+            }
+            Err(ReadError::Parse(e)) => {
+                eprint!("{}", e.with_file(&path));
+                failed = true;
+            }
+            Err(ReadError::Io(e)) => {
+                eprintln!("error: cannot read {}: {}", path.display(), e);
+                failed = true;
+            }
+        }
+    }
+
+    match options.format {
+        Format::Table if !day_results.is_empty() => {
+            report::print_table(&day_results);
+            if options.profile {
+                println!();
+                report::print_profile(&day_results);
+            }
+        }
+        Format::Table => {}
+        Format::Json => report::print_json(&day_results, options.profile),
+    }
+
+    if options.verify.is_some() && !verify::print_summary(&checks) {
+        failed = true;
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,305 @@
+use common::Palette;
+use std::path::{Path, PathBuf};
+
+pub const USAGE: &str = "\
+Usage: aoc <DAYS> <INPUT> [--part <PART>] [--format <FORMAT>] [--verify <ANSWERS>]
+                          [--jobs <JOBS>] [--profile]
+       aoc <DAYS> <INPUT> --render <TARGET> [--scale <SCALE>] [--palette <PALETTE>]
+       aoc <DAYS> [<INPUT>] --generate <SEED> [--size <SIZE>] [OPTIONS]
+
+Arguments:
+  <DAYS>   a day (7), a range of days (1-25), or a comma separated list of either
+  <INPUT>  an input file, or - for standard input, when a single day is
+           selected, or a directory holding day-NN.txt or day-NN/input.txt
+           for each selected day; days 1, 2, 3, 5, 8, 10, 18, 22 and 24 parse
+           their input a line at a time as it is read; with
+           --generate, an optional directory to save the generated inputs to,
+           as day-NN.txt
+
+Options:
+  --part <PART>        run only part 1 or part 2 (default: both)
+  --format <FORMAT>    print a table, or json with one object per line for
+                       each day (default: table)
+  --verify <ANSWERS>   compare each answer with the expected answer in the
+                       ANSWERS directory, laid out like <INPUT>: the answers
+                       for day-NN.txt are day-NN.part-1 and day-NN.part-2
+  --jobs <JOBS>        solve up to JOBS days, and parts of days, at once on
+                       separate threads; output is in the same order as
+                       with one job (default: 1)
+  --profile            also report the time taken and the peak heap usage
+                       of parsing and of each part; the peak is the most
+                       memory allocated at once beyond what was already in
+                       use when the phase started; requires one job, as
+                       memory is counted for the whole process
+  --render <TARGET>    instead of solving, record each step of the simulation
+                       on days 11, 13, 20, 23 and 25, and replay it in the
+                       terminal, when TARGET is -, or write it to the TARGET
+                       directory as images day-NN-0000.pgm (or .ppm), ...
+  --scale <SCALE>      draw each cell SCALE times larger (default: 1)
+  --palette <PALETTE>  gray, heat, or a comma separated list of colors, such
+                       as 000000,ff8800,ffffff, to draw levels from lowest to
+                       highest with; gray palettes make PGM images, and others
+                       PPM images (default: gray)
+  --generate <SEED>    instead of reading <INPUT>, generate a random input for
+                       each day from SEED
+  --size <SIZE>        the size of each generated input, in the day's own
+                       units: lines, crabs, scanners, ... (default: the size
+                       of a personal puzzle input)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Terminal,
+    Images(PathBuf),
+}
+
+#[derive(Debug)]
+pub struct Render {
+    pub target: Target,
+    pub scale: usize,
+    pub palette: Palette,
+}
+
+#[derive(Debug)]
+pub struct Options {
+    pub days: Vec<u8>,
+    pub input: Option<PathBuf>,
+    pub parts: Vec<u8>,
+    pub format: Format,
+    pub verify: Option<PathBuf>,
+    pub jobs: usize,
+    pub profile: bool,
+    pub render: Option<Render>,
+    pub generate: Option<u64>,
+    pub size: Option<usize>,
+}
+
+fn parse_day(text: &str) -> Result<u8, String> {
+    match text.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day {:?}, expected 1 to 25", text)),
+    }
+}
+
+fn parse_days(text: &str) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+
+    for item in text.split(',') {
+        if let Some((first, last)) = item.split_once('-') {
+            let first = parse_day(first)?;
+            let last = parse_day(last)?;
+            if first > last {
+                return Err(format!("invalid range of days {:?}", item));
+            }
+            days.extend(first..=last);
+        } else {
+            days.push(parse_day(item)?);
+        }
+    }
+
+    days.sort_unstable();
+    days.dedup();
+
+    Ok(days)
+}
+
+impl Options {
+    pub fn parse(args: impl Iterator<Item = String>) -> Result<Options, String> {
+        let mut positionals = Vec::new();
+        let mut parts = vec![1, 2];
+        let mut format = Format::Table;
+        let mut verify = None;
+        let mut jobs = 1;
+        let mut profile = false;
+        let mut target = None;
+        let mut scale = 1;
+        let mut palette = None;
+        let mut generate = None;
+        let mut size = None;
+
+        let mut args = args;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    let part = args.next().ok_or("--part requires a value")?;
+                    parts = match part.as_str() {
+                        "1" => vec![1],
+                        "2" => vec![2],
+                        _ => return Err(format!("invalid part {:?}, expected 1 or 2", part)),
+                    };
+                }
+                "--format" => {
+                    let name = args.next().ok_or("--format requires a value")?;
+                    format = match name.as_str() {
+                        "table" => Format::Table,
+                        "json" => Format::Json,
+                        _ => {
+                            return Err(format!(
+                                "invalid format {:?}, expected table or json",
+                                name
+                            ))
+                        }
+                    };
+                }
+                "--verify" => {
+                    let answers = args.next().ok_or("--verify requires a directory")?;
+                    verify = Some(PathBuf::from(answers));
+                }
+                "--jobs" => {
+                    let text = args.next().ok_or("--jobs requires a value")?;
+                    jobs = match text.parse::<usize>() {
+                        Ok(jobs) if jobs > 0 => jobs,
+                        _ => return Err(format!("invalid number of jobs {:?}", text)),
+                    };
+                }
+                "--profile" => profile = true,
+                "--render" => {
+                    let text = args.next().ok_or("--render requires a target")?;
+                    target = Some(match text.as_str() {
+                        "-" => Target::Terminal,
+                        _ => Target::Images(PathBuf::from(text)),
+                    });
+                }
+                "--scale" => {
+                    let text = args.next().ok_or("--scale requires a value")?;
+                    scale = match text.parse::<usize>() {
+                        Ok(scale) if scale > 0 => scale,
+                        _ => return Err(format!("invalid scale {:?}", text)),
+                    };
+                }
+                "--generate" => {
+                    let text = args.next().ok_or("--generate requires a seed")?;
+                    let seed = text
+                        .parse::<u64>()
+                        .map_err(|_| format!("invalid seed {:?}", text))?;
+                    generate = Some(seed);
+                }
+                "--size" => {
+                    let text = args.next().ok_or("--size requires a value")?;
+                    let value = text
+                        .parse::<usize>()
+                        .map_err(|_| format!("invalid size {:?}", text))?;
+                    size = Some(value);
+                }
+                "--palette" => {
+                    let text = args.next().ok_or("--palette requires a value")?;
+                    palette = Some(Palette::parse(&text)?);
+                }
+                _ if arg.starts_with("--") => {
+                    return Err(format!("unknown option {}", arg));
+                }
+                _ => positionals.push(arg),
+            }
+        }
+
+        match (positionals.len(), generate) {
+            (2, _) | (1, Some(_)) => {}
+            _ => return Err(String::from("expected <DAYS> and <INPUT>")),
+        }
+        if size.is_some() && generate.is_none() {
+            return Err(String::from("--size requires --generate"));
+        }
+        if profile && jobs > 1 {
+            return Err(String::from("--profile requires --jobs 1"));
+        }
+
+        let days = parse_days(&positionals[0])?;
+        let input = positionals.get(1).map(PathBuf::from);
+
+        let render = match target {
+            Some(target) => Some(Render {
+                target,
+                scale,
+                palette: match palette {
+                    Some(palette) => palette,
+                    None => Palette::parse("gray")?,
+                },
+            }),
+            None if palette.is_some() || scale != 1 => {
+                return Err(String::from("--scale and --palette require --render"))
+            }
+            None => None,
+        };
+
+        Ok(Options {
+            days,
+            input,
+            parts,
+            format,
+            verify,
+            jobs,
+            profile,
+            render,
+            generate,
+            size,
+        })
+    }
+
+    // Locate the puzzle input for one of the selected days
+    pub fn input_path(&self, day: u8) -> Result<PathBuf, String> {
+        let input = self.input.as_ref().ok_or("no <INPUT> given")?;
+
+        if !input.is_dir() {
+            if self.days.len() > 1 {
+                return Err(format!(
+                    "{} is not a directory, but several days are selected",
+                    input.display()
+                ));
+            }
+            return Ok(input.clone());
+        }
+
+        let candidates = [
+            input.join(format!("day-{:02}.txt", day)),
+            input.join(format!("day-{:02}", day)).join("input.txt"),
+        ];
+
+        candidates
+            .iter()
+            .find(|candidate| candidate.is_file())
+            .cloned()
+            .ok_or_else(|| format!("no input for day {} in {}", day, input.display()))
+    }
+
+    // Locate the expected answer to one part for the input at input_path,
+    // when verifying
+    pub fn answer_path(&self, input_path: &Path, part: u8) -> Option<PathBuf> {
+        let answers = self.verify.as_ref()?;
+        let input = self.input.as_ref()?;
+        let relative = if input.is_dir() {
+            input_path.strip_prefix(input).ok()?
+        } else {
+            Path::new(input_path.file_name()?)
+        };
+
+        Some(
+            answers
+                .join(relative)
+                .with_extension(format!("part-{}", part)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_single_range_and_list() {
+        assert_eq!(parse_days("7"), Ok(vec![7]));
+        assert_eq!(parse_days("3-5"), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days("9,1-2,2"), Ok(vec![1, 2, 9]));
+    }
+
+    #[test]
+    fn days_out_of_range() {
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("5-3").is_err());
+    }
+}
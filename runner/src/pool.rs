@@ -0,0 +1,109 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// A limit on the number of threads working at once, shared by nested calls
+/// to `map`, so that the parts of a day can run on threads left idle by the
+/// other days without exceeding the limit.
+#[derive(Debug)]
+pub struct Pool {
+    // Threads which may be started, beyond those already working
+    spare: AtomicUsize,
+}
+
+impl Pool {
+    /// A pool of `jobs` threads at most, counting the calling thread.
+    pub fn new(jobs: usize) -> Pool {
+        Pool {
+            spare: AtomicUsize::new(jobs.saturating_sub(1)),
+        }
+    }
+
+    // Take up to `wanted` spare threads
+    fn acquire(&self, wanted: usize) -> usize {
+        let mut spare = self.spare.load(Ordering::Relaxed);
+        loop {
+            let taken = spare.min(wanted);
+            match self.spare.compare_exchange_weak(
+                spare,
+                spare - taken,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return taken,
+                Err(actual) => spare = actual,
+            }
+        }
+    }
+
+    fn release(&self) {
+        self.spare.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Apply `f` to each item, using the calling thread and any spare
+    /// threads, and return the results in the order of the items.
+    pub fn map<T, R, F>(&self, items: Vec<T>, f: F) -> Vec<R>
+    where
+        T: Send,
+        R: Send,
+        F: Fn(T) -> R + Sync,
+    {
+        let count = items.len();
+        let queue = Mutex::new(items.into_iter().enumerate().collect::<VecDeque<_>>());
+        let results = Mutex::new((0..count).map(|_| None).collect::<Vec<Option<R>>>());
+
+        let work = || loop {
+            let Some((j, item)) = queue.lock().unwrap().pop_front() else {
+                break;
+            };
+            let result = f(item);
+            results.lock().unwrap()[j] = Some(result);
+        };
+
+        thread::scope(|scope| {
+            for _ in 0..self.acquire(count.saturating_sub(1)) {
+                scope.spawn(|| {
+                    work();
+                    self.release();
+                });
+            }
+            work();
+        });
+
+        results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .map(|result| result.expect("every item is mapped"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_in_order_within_the_limit() {
+        let pool = Pool::new(3);
+        let working = AtomicUsize::new(0);
+        let most = AtomicUsize::new(0);
+
+        let results = pool.map((0..8).collect(), |j: u64| {
+            // Nested maps share the same limit
+            pool.map(vec![j, j + 100], |k| {
+                let now = working.fetch_add(1, Ordering::SeqCst) + 1;
+                most.fetch_max(now, Ordering::SeqCst);
+                thread::sleep(std::time::Duration::from_millis(5));
+                working.fetch_sub(1, Ordering::SeqCst);
+                k * 2
+            })
+        });
+
+        let expected: Vec<Vec<u64>> = (0..8).map(|j| vec![j * 2, (j + 100) * 2]).collect();
+        assert_eq!(results, expected);
+        assert!(most.load(Ordering::SeqCst) <= 3);
+        assert_eq!(pool.spare.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::days::DayResult;
+use common::Answer;
+use std::time::Duration;
+
+fn answer_lines(answer: &Answer) -> Vec<String> {
+    match answer {
+        Answer::Lines(lines) => lines.clone(),
+        _ => vec![answer.to_string()],
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut result = String::from("\"");
+
+    for ch in text.chars() {
+        match ch {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            ch if ch.is_control() => result.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => result.push(ch),
+        }
+    }
+    result.push('"');
+
+    result
+}
+
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(number) => number.to_string(),
+        Answer::Text(text) => json_string(text),
+        Answer::Lines(lines) => {
+            let lines: Vec<String> = lines.iter().map(|line| json_string(line)).collect();
+            format!("[{}]", lines.join(", "))
+        }
+        Answer::Empty | Answer::Unsolvable(_) => String::from("null"),
+    }
+}
+
+// Print one JSON object per line for each day, for consumption by other
+// tools. Times are in nanoseconds, and multi-line answers (such as day 13's
+// activation code) are arrays of strings. Parts with no answer have a null
+// answer and the reason in "no_answer". When profiling, the peak heap
+// usage of each phase is included in bytes.
+pub fn print_json(day_results: &[DayResult], profile: bool) {
+    for day_result in day_results.iter() {
+        let parts: Vec<String> = day_result
+            .parts
+            .iter()
+            .filter(|part_result| part_result.answer != Answer::Empty)
+            .map(|part_result| {
+                let no_answer = match &part_result.answer {
+                    Answer::Unsolvable(reason) => {
+                        format!(", \"no_answer\": {}", json_string(reason))
+                    }
+                    _ => String::new(),
+                };
+                let peak = if profile {
+                    format!(", \"peak_bytes\": {}", part_result.peak_bytes)
+                } else {
+                    String::new()
+                };
+                format!(
+                    "{{\"part\": {}, \"answer\": {}{}, \"elapsed_ns\": {}{}}}",
+                    part_result.part,
+                    json_answer(&part_result.answer),
+                    no_answer,
+                    part_result.elapsed.as_nanos(),
+                    peak
+                )
+            })
+            .collect();
+        let parse_peak = if profile {
+            format!(", \"parse_peak_bytes\": {}", day_result.parse_peak_bytes)
+        } else {
+            String::new()
+        };
+
+        println!(
+            "{{\"day\": {}, \"input_hash\": \"{:016x}\", \"parse_elapsed_ns\": {}{}, \"parts\": [{}]}}",
+            day_result.day,
+            day_result.input_hash,
+            day_result.parse_elapsed.as_nanos(),
+            parse_peak,
+            parts.join(", ")
+        );
+    }
+}
+
+// A number of bytes in the largest binary unit which keeps it at least 1
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+// Print the time taken and peak heap usage of parsing and of each part,
+// one row per phase
+pub fn print_profile(day_results: &[DayResult]) {
+    println!("Day  Phase   {:>10}  {:>10}", "Time", "Peak heap");
+    println!("---  ------  {:->10}  {:->10}", "", "");
+
+    for day_result in day_results.iter() {
+        println!(
+            "{:>3}  {:<6}  {:>10}  {:>10}",
+            day_result.day,
+            "parse",
+            format!("{:.1?}", day_result.parse_elapsed),
+            format_bytes(day_result.parse_peak_bytes)
+        );
+        for part_result in day_result.parts.iter() {
+            println!(
+                "{:>3}  {:<6}  {:>10}  {:>10}",
+                day_result.day,
+                format!("part {}", part_result.part),
+                format!("{:.1?}", part_result.elapsed),
+                format_bytes(part_result.peak_bytes)
+            );
+        }
+    }
+}
+
+// Print a summary table with one row per part. Answers spanning several
+// lines (such as day 13's activation code) continue on the rows below.
+pub fn print_table(day_results: &[DayResult]) {
+    let mut rows = Vec::new();
+    let mut total = Duration::ZERO;
+
+    for day_result in day_results.iter() {
+        total += day_result.parse_elapsed;
+
+        for part_result in day_result.parts.iter() {
+            total += part_result.elapsed;
+
+            if part_result.answer == Answer::Empty {
+                continue;
+            }
+
+            let mut lines = answer_lines(&part_result.answer).into_iter();
+            rows.push((
+                day_result.day.to_string(),
+                part_result.part.to_string(),
+                lines.next().unwrap_or_default(),
+                format!("{:.1?}", part_result.elapsed),
+            ));
+            for line in lines {
+                rows.push((String::new(), String::new(), line, String::new()));
+            }
+        }
+    }
+
+    let answer_width = rows
+        .iter()
+        .map(|(_, _, answer, _)| answer.len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!(
+        "Day  Part  {:<w$}  {:>10}",
+        "Answer",
+        "Time",
+        w = answer_width
+    );
+    println!("---  ----  {:-<w$}  {:->10}", "", "", w = answer_width);
+    for (day, part, answer, time) in rows.iter() {
+        let row = format!(
+            "{:>3}  {:>4}  {:<w$}  {:>10}",
+            day,
+            part,
+            answer,
+            time,
+            w = answer_width
+        );
+        println!("{}", row.trim_end());
+    }
+    println!();
+    println!("Total time, including parsing: {:.1?}", total);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::NoAnswer;
+
+    #[test]
+    fn json_answers() {
+        assert_eq!(json_answer(&Answer::from(26984457539u64)), "26984457539");
+        assert_eq!(json_answer(&Answer::from("7499")), "\"7499\"");
+        assert_eq!(
+            json_answer(&Answer::from(vec![String::from("#.\"\\")])),
+            "[\"#.\\\"\\\\\"]"
+        );
+        assert_eq!(
+            json_answer(&Answer::from(Err::<u32, _>(NoAnswer::new("no board wins")))),
+            "null"
+        );
+    }
+
+    #[test]
+    fn byte_sizes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 << 30), "5.0 GiB");
+    }
+}
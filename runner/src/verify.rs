@@ -0,0 +1,139 @@
+use crate::days::DayResult;
+use common::Answer;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Match,
+    Mismatch { expected: String },
+    Missing,
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub actual: String,
+    pub outcome: Outcome,
+}
+
+// Expected answers are stored one per file, exactly as the answer is
+// printed, so that multi-line answers need no escaping
+fn expected_answer(path: &Path) -> Option<String> {
+    let text = fs::read_to_string(path).ok()?;
+
+    Some(text.trim_end().to_string())
+}
+
+// Compare each part's answer with the expected answer found at
+// answer_path(part). Parts without an answer, such as day 25 part 2, are
+// not checked.
+pub fn check(day_result: &DayResult, answer_path: impl Fn(u8) -> Option<PathBuf>) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for part_result in day_result.parts.iter() {
+        if part_result.answer == Answer::Empty {
+            continue;
+        }
+
+        let actual = part_result.answer.to_string().trim_end().to_string();
+        let outcome = match answer_path(part_result.part).and_then(|path| expected_answer(&path)) {
+            Some(expected) if expected == actual => Outcome::Match,
+            Some(expected) => Outcome::Mismatch { expected },
+            None => Outcome::Missing,
+        };
+
+        checks.push(Check {
+            day: day_result.day,
+            part: part_result.part,
+            actual,
+            outcome,
+        });
+    }
+
+    checks
+}
+
+fn indent(text: &str) -> String {
+    text.replace('\n', "\n            ")
+}
+
+// Report every mismatch, followed by a one line summary, on stderr so as
+// not to interleave with the answers. Returns whether all of the checked
+// answers matched.
+pub fn print_summary(checks: &[Check]) -> bool {
+    let mut matches = 0;
+    let mut mismatches = 0;
+    let mut missing = 0;
+
+    for check in checks.iter() {
+        match &check.outcome {
+            Outcome::Match => matches += 1,
+            Outcome::Mismatch { expected } => {
+                mismatches += 1;
+                eprintln!("mismatch: day {} part {}", check.day, check.part);
+                eprintln!("  expected: {}", indent(expected));
+                eprintln!("    actual: {}", indent(&check.actual));
+            }
+            Outcome::Missing => missing += 1,
+        }
+    }
+
+    eprintln!(
+        "Verified {} answers: {} matched, {} mismatched, {} without an expected answer",
+        checks.len(),
+        matches,
+        mismatches,
+        missing
+    );
+
+    mismatches == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::pool::Pool;
+
+    // Every day ships its puzzle samples in day-NN/samples, as <name>.txt
+    // with the expected answers in <name>.part-1 and <name>.part-2
+    #[test]
+    fn samples() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let mut failures = Vec::new();
+        let pool = Pool::new(2);
+
+        for day in 1..=25 {
+            let samples = root.join(format!("day-{:02}", day)).join("samples");
+            let mut inputs: Vec<PathBuf> = fs::read_dir(&samples)
+                .unwrap_or_else(|e| panic!("cannot read {}: {}", samples.display(), e))
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                .collect();
+            inputs.sort();
+            assert!(!inputs.is_empty(), "no samples for day {}", day);
+
+            for input_path in inputs.iter() {
+                let input = fs::read_to_string(input_path).unwrap();
+                let day_result = days::run(day, &input, &[1, 2], &pool).unwrap();
+                let answer_path = |part| Some(input_path.with_extension(format!("part-{}", part)));
+
+                for check in check(&day_result, answer_path) {
+                    if let Outcome::Mismatch { expected } = check.outcome {
+                        failures.push(format!(
+                            "{} part {}: expected {:?}, got {:?}",
+                            input_path.display(),
+                            check.part,
+                            expected,
+                            check.actual
+                        ));
+                    }
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}